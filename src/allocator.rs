@@ -0,0 +1,11 @@
+// Stable stand-in for the unstable `allocator_api`. Everything
+// allocator-aware in the crate names the API through these re-exports, so
+// moving to `core::alloc::Allocator` once it stabilises only touches this
+// file.
+pub use allocator_api2::alloc::{AllocError, Allocator, Global};
+
+/// A `Vec` that allocates from `A` instead of the global allocator.
+///
+/// This is allocator-api2's `Vec`, a separate type from
+/// `alloc::vec::Vec<T, A>`, which needs the unstable `allocator_api`.
+pub type AllocVec<T, A = Global> = allocator_api2::vec::Vec<T, A>;
@@ -0,0 +1,132 @@
+use core::iter::Zip;
+
+use crate::{Error, Map, MapMut};
+
+/// A multiset: a set that counts how many times each item occurs.
+///
+/// Implemented for every [`Map`] from items to `usize` counts, so any map
+/// backend doubles as a bag.
+pub trait Bag<T> {
+    type Counts<'a>: Iterator<Item = (&'a T, usize)>
+    where
+        T: 'a,
+        Self: 'a;
+
+    /// Number of occurrences of `item`, zero if absent.
+    fn count(&self, item: &T) -> usize;
+    /// Number of distinct items.
+    fn distinct_len(&self) -> usize;
+    /// Number of occurrences across all items.
+    fn total_len(&self) -> usize;
+    /// Iterates over each distinct item with its count.
+    fn counts(&self) -> Self::Counts<'_>;
+
+    /// Writes the multiset union (the larger count of each item) of `self`
+    /// and `other` into `out`.
+    fn union_into<B, O>(&self, other: &B, out: &mut O) -> Result<(), Error<T>>
+    where
+        B: Bag<T>,
+        O: BagMut<T>,
+        T: Clone,
+    {
+        for (item, n) in self.counts() {
+            out.insert_n(item.clone(), n.max(other.count(item)))?;
+        }
+        for (item, n) in other.counts() {
+            if self.count(item) == 0 {
+                out.insert_n(item.clone(), n)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the multiset intersection (the smaller count of each item) of
+    /// `self` and `other` into `out`.
+    fn intersection_into<B, O>(&self, other: &B, out: &mut O) -> Result<(), Error<T>>
+    where
+        B: Bag<T>,
+        O: BagMut<T>,
+        T: Clone,
+    {
+        for (item, n) in self.counts() {
+            let n = n.min(other.count(item));
+            if n > 0 {
+                out.insert_n(item.clone(), n)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Maps that hand out mutable access to the value stored under a key,
+/// which [`BagMut`] needs to adjust counts in place.
+pub trait GetMut<K, V> {
+    fn get_mut(&mut self, k: &K) -> Option<&mut V>;
+}
+
+pub trait BagMut<T>: Bag<T> {
+    /// Adds `n` occurrences of `item`, returning its new count.
+    fn insert_n(&mut self, item: T, n: usize) -> Result<usize, Error<T>>;
+    /// Removes up to `n` occurrences of `item`, returning how many were
+    /// removed.
+    fn remove_n(&mut self, item: &T, n: usize) -> usize;
+}
+
+type Counts<'a, T, K, V> = core::iter::Map<Zip<K, V>, fn((&'a T, &'a usize)) -> (&'a T, usize)>;
+
+impl<T, M: Map<T, usize>> Bag<T> for M {
+    type Counts<'a>
+        = Counts<'a, T, M::Keys<'a>, M::Values<'a>>
+    where
+        T: 'a,
+        Self: 'a;
+
+    #[inline(always)]
+    fn count(&self, item: &T) -> usize {
+        self.get(item).copied().unwrap_or(0)
+    }
+
+    #[inline(always)]
+    fn distinct_len(&self) -> usize {
+        self.len()
+    }
+
+    /// Saturates at `usize::MAX` rather than overflowing.
+    fn total_len(&self) -> usize {
+        self.values().fold(0, |total, n| total.saturating_add(*n))
+    }
+
+    fn counts(&self) -> Self::Counts<'_> {
+        self.keys().zip(self.values()).map(|(item, n)| (item, *n))
+    }
+}
+
+impl<T, M: Map<T, usize> + MapMut<T, usize> + GetMut<T, usize>> BagMut<T> for M {
+    fn insert_n(&mut self, item: T, n: usize) -> Result<usize, Error<T>> {
+        if let Some(count) = self.get_mut(&item) {
+            *count = count.saturating_add(n);
+            return Ok(*count);
+        }
+        if n == 0 {
+            return Ok(0);
+        }
+        match self.try_insert(item, n) {
+            Ok(_) => Ok(n),
+            Err(Error::InsertFailed((item, _))) => Err(Error::InsertFailed(item)),
+            Err(Error::CapacityExceeded) => Err(Error::CapacityExceeded),
+        }
+    }
+
+    fn remove_n(&mut self, item: &T, n: usize) -> usize {
+        let Some(count) = self.get_mut(item) else {
+            return 0;
+        };
+        if *count > n {
+            *count -= n;
+            return n;
+        }
+        let removed = *count;
+        MapMut::remove(self, item);
+        removed
+    }
+}
@@ -0,0 +1,660 @@
+use core::ops::RangeBounds;
+
+use crate::list::to_range;
+use crate::{
+    Collection, CollectionMut, Error, GetMut, Iterable, IterableMut, List, ListDrain, ListMut,
+    ListNew, ListResizable, ListSliceMut, ListSortable, Map, MapMut, Set, SetMut,
+};
+
+/// Adapter that enforces a runtime capacity limit on any mutable collection.
+///
+/// Insertions that would grow the wrapped collection beyond `limit` are
+/// rejected with the same errors a fixed-capacity backend such as
+/// `heapless::Vec` reports, handing the rejected item back to the caller.
+#[derive(Debug, Clone)]
+pub struct Bounded<C> {
+    inner: C,
+    limit: usize,
+}
+
+impl<C> Bounded<C> {
+    /// Wraps `inner`, refusing any insertion that would make it hold more than
+    /// `limit` elements.
+    ///
+    /// A collection that already exceeds `limit` is kept as is, but will not
+    /// accept new elements until it shrinks below the limit.
+    pub fn with_limit(inner: C, limit: usize) -> Self {
+        Self { inner, limit }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    #[inline(always)]
+    fn has_room_for<T>(&self, additional: usize) -> bool
+    where
+        C: Collection<T>,
+    {
+        self.inner
+            .len()
+            .checked_add(additional)
+            .map_or(false, |len| len <= self.limit)
+    }
+}
+
+impl<T, C: AsRef<[T]>> AsRef<[T]> for Bounded<C> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        self.inner.as_ref()
+    }
+}
+
+/// Mutating elements in place never changes the length, so the limit cannot
+/// be bypassed through the slice.
+impl<T, C: AsMut<[T]>> AsMut<[T]> for Bounded<C> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.inner.as_mut()
+    }
+}
+
+impl<C: Iterable> Iterable for Bounded<C> {
+    type Item<'collection>
+        = C::Item<'collection>
+    where
+        Self: 'collection;
+    type Iterator<'collection>
+        = C::Iterator<'collection>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.inner.iter()
+    }
+}
+
+impl<C: IterableMut> IterableMut for Bounded<C> {
+    type ItemMut<'collection>
+        = C::ItemMut<'collection>
+    where
+        Self: 'collection;
+    type IteratorMut<'collection>
+        = C::IteratorMut<'collection>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        self.inner.iter_mut()
+    }
+}
+
+impl<T, C: Collection<T>> Collection<T> for Bounded<C> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.contains(other)
+    }
+}
+
+impl<T, C: CollectionMut<T>> CollectionMut<T> for Bounded<C> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<T, L: List<T>> List<T> for Bounded<L> {
+    type Slice<'a>
+        = L::Slice<'a>
+    where
+        T: 'a,
+        Self: 'a;
+
+    #[inline(always)]
+    fn as_slice(&self) -> Self::Slice<'_> {
+        self.inner.as_slice()
+    }
+
+    #[inline(always)]
+    fn find_index(&self, other: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.inner.find_index(other)
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Option<&T> {
+        self.inner.first()
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Option<&T> {
+        self.inner.last()
+    }
+
+    #[inline(always)]
+    fn get(&self, index: usize) -> Option<&T> {
+        self.inner.get(index)
+    }
+
+    #[inline(always)]
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.inner.binary_search(x)
+    }
+
+    #[inline(always)]
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        self.inner.binary_search_by(f)
+    }
+
+    #[inline(always)]
+    fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.inner.binary_search_by_key(b, f)
+    }
+
+    #[inline(always)]
+    fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.starts_with(needle)
+    }
+
+    #[inline(always)]
+    fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.ends_with(needle)
+    }
+}
+
+impl<T, L: ListNew<T>> ListNew<T> for Bounded<L> {
+    /// Creates an empty list with no effective limit; use
+    /// [`Bounded::with_limit`] to configure one.
+    #[inline(always)]
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self::with_limit(L::new(), usize::MAX)
+    }
+}
+
+impl<T, L: ListMut<T>> ListMut<T> for Bounded<L> {
+    type ElementMut<'a>
+        = L::ElementMut<'a>
+    where
+        T: 'a,
+        Self: 'a;
+
+    fn push(&mut self, item: T) -> Result<(), Error<T>> {
+        if !self.has_room_for::<T>(1) {
+            return Err(Error::InsertFailed(item));
+        }
+        self.inner.push(item)
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.inner.capacity().min(self.limit)
+    }
+
+    #[inline(always)]
+    fn first_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.inner.first_mut()
+    }
+
+    #[inline(always)]
+    fn last_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.inner.last_mut()
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self, index: usize) -> Option<Self::ElementMut<'_>> {
+        self.inner.get_mut(index)
+    }
+
+    fn insert(&mut self, index: usize, element: T) -> Result<(), Error<T>> {
+        if !self.has_room_for::<T>(1) {
+            return Err(Error::InsertFailed(element));
+        }
+        self.inner.insert(index, element)
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, index: usize) -> T {
+        self.inner.remove(index)
+    }
+
+    #[inline(always)]
+    fn swap_remove(&mut self, index: usize) -> T {
+        self.inner.swap_remove(index)
+    }
+
+    #[inline(always)]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.inner.swap(a, b)
+    }
+
+    #[inline(always)]
+    fn reverse(&mut self) {
+        self.inner.reverse()
+    }
+
+    #[inline(always)]
+    fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len)
+    }
+
+    #[inline(always)]
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.inner.retain(f)
+    }
+
+    #[inline(always)]
+    fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.inner.retain_mut(f)
+    }
+
+    #[inline(always)]
+    fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.inner.dedup()
+    }
+
+    #[inline(always)]
+    fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.inner.dedup_by(same_bucket)
+    }
+
+    #[inline(always)]
+    fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.inner.dedup_by_key(key)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.inner.fill(value)
+    }
+
+    #[inline(always)]
+    fn fill_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.inner.fill_with(f)
+    }
+
+    fn append(&mut self, other: &mut Self) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        if !self.has_room_for::<T>(other.inner.len()) {
+            return Err(Error::CapacityExceeded);
+        }
+        self.inner.append(&mut other.inner)
+    }
+
+    #[inline(always)]
+    fn split_off(&mut self, at: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self::with_limit(self.inner.split_off(at), self.limit)
+    }
+}
+
+impl<T, L: ListResizable<T>> ListResizable<T> for Bounded<L> {
+    fn resize(&mut self, new_len: usize, value: T) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        if new_len > self.inner.len() && new_len > self.limit {
+            return Err(Error::CapacityExceeded);
+        }
+        self.inner.resize(new_len, value)
+    }
+
+    fn resize_with<F>(&mut self, new_len: usize, f: F) -> Result<(), Error<T>>
+    where
+        F: FnMut() -> T,
+    {
+        if new_len > self.inner.len() && new_len > self.limit {
+            return Err(Error::CapacityExceeded);
+        }
+        self.inner.resize_with(new_len, f)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let room = self.limit.saturating_sub(self.inner.len());
+        self.inner.reserve(additional.min(room))
+    }
+
+    #[inline(always)]
+    fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+}
+
+impl<T, L: ListSortable<T>> ListSortable<T> for Bounded<L> {
+    #[inline(always)]
+    fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.inner.sort()
+    }
+
+    #[inline(always)]
+    fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        self.inner.sort_by(compare)
+    }
+
+    #[inline(always)]
+    fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.inner.sort_by_key(f)
+    }
+
+    #[inline(always)]
+    fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.inner.sort_unstable()
+    }
+
+    #[inline(always)]
+    fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        self.inner.sort_unstable_by(compare)
+    }
+
+    #[inline(always)]
+    fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.inner.sort_unstable_by_key(f)
+    }
+}
+
+impl<T, L: ListDrain<T>> ListDrain<T> for Bounded<L> {
+    type Drain<'a>
+        = L::Drain<'a>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type Splice<'a>
+        = L::Splice<'a>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type ExtractIf<'a, F>
+        = L::ExtractIf<'a, F>
+    where
+        T: 'a,
+        Self: 'a,
+        F: FnMut(&mut T) -> bool;
+
+    #[inline(always)]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.inner.drain(range)
+    }
+
+    fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<Self::Splice<'_>, Error<T>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let range = to_range(range, self.inner.len());
+        let replace_with = replace_with.into_iter();
+        if self.inner.len() - range.len() + replace_with.len() > self.limit {
+            return Err(Error::CapacityExceeded);
+        }
+        self.inner.splice(range, replace_with)
+    }
+
+    #[inline(always)]
+    fn extract_if<R, F>(&mut self, range: R, filter: F) -> Self::ExtractIf<'_, F>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool,
+    {
+        self.inner.extract_if(range, filter)
+    }
+
+    fn extend_from_slice(&mut self, other: &[T]) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        if !self.has_room_for::<T>(other.len()) {
+            return Err(Error::CapacityExceeded);
+        }
+        self.inner.extend_from_slice(other)
+    }
+
+    fn extend_from_within<R>(&mut self, src: R) -> Result<(), Error<T>>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let src = to_range(src, self.inner.len());
+        if !self.has_room_for::<T>(src.len()) {
+            return Err(Error::CapacityExceeded);
+        }
+        self.inner.extend_from_within(src)
+    }
+}
+
+/// Like [`AsMut`], slice access never changes the length.
+impl<T, L: ListSliceMut<T>> ListSliceMut<T> for Bounded<L> {
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.inner.as_mut_slice()
+    }
+}
+
+impl<K, V, M: Map<K, V>> Map<K, V> for Bounded<M> {
+    type Keys<'key>
+        = M::Keys<'key>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = M::Values<'value>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn get(&self, k: &K) -> Option<&V> {
+        self.inner.get(k)
+    }
+
+    #[inline(always)]
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)>
+    where
+        K: PartialEq,
+    {
+        self.inner.get_key_value(k)
+    }
+
+    #[inline(always)]
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        self.inner.keys()
+    }
+
+    #[inline(always)]
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        self.inner.values()
+    }
+}
+
+impl<K, V, M: Map<K, V> + MapMut<K, V>> MapMut<K, V> for Bounded<M> {
+    type ValuesMut<'value>
+        = M::ValuesMut<'value>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn values_mut<'c>(&'c mut self) -> Self::ValuesMut<'c> {
+        self.inner.values_mut()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if inserting a new key would exceed the limit. Use
+    /// [`MapMut::try_insert`] to handle that case.
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.try_insert(key, value) {
+            Ok(old) => old,
+            Err(_) => panic!("Bounded map limit of {} exceeded", self.limit),
+        }
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.inner.remove(key)
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.inner.capacity().min(self.limit)
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Error<(K, V)>> {
+        if Map::len(&self.inner) >= self.limit && self.inner.get(&key).is_none() {
+            return Err(Error::InsertFailed((key, value)));
+        }
+        self.inner.try_insert(key, value)
+    }
+}
+
+impl<K, V, M: GetMut<K, V>> GetMut<K, V> for Bounded<M> {
+    #[inline(always)]
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        self.inner.get_mut(k)
+    }
+}
+
+impl<T, S: Set<T>> Set<T> for Bounded<S> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        Set::len(&self.inner)
+    }
+
+    #[inline(always)]
+    fn get<'a>(&'a self, value: &'a T) -> Option<&'a T> {
+        self.inner.get(value)
+    }
+}
+
+impl<T, S: Set<T> + SetMut<T>> SetMut<T> for Bounded<S> {
+    /// # Panics
+    ///
+    /// Panics if inserting a new value would exceed the limit. Use
+    /// [`SetMut::try_insert`] to handle that case.
+    fn insert(&mut self, value: T) -> bool {
+        match self.try_insert(value) {
+            Ok(inserted) => inserted,
+            Err(_) => panic!("Bounded set limit of {} exceeded", self.limit),
+        }
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, value: &T) -> bool {
+        self.inner.remove(value)
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.inner.capacity().min(self.limit)
+    }
+
+    fn try_insert(&mut self, value: T) -> Result<bool, Error<T>> {
+        if Set::len(&self.inner) >= self.limit && self.inner.get(&value).is_none() {
+            return Err(Error::InsertFailed(value));
+        }
+        self.inner.try_insert(value)
+    }
+}
@@ -0,0 +1,694 @@
+use core::marker::PhantomData;
+
+use crate::{
+    Collection, CollectionMut, Error, GetMut, Iterable, IterableMut, List, ListMut, ListNew, Map,
+    MapMut,
+};
+
+#[derive(Debug, Clone)]
+enum State<B, O> {
+    Borrowed(B),
+    Owned(O),
+}
+
+/// List that reads through a borrowed slice until it is first changed.
+///
+/// Every [`ListMut`] method clones the slice into an owned `L` before doing
+/// its work, so after a mutating call the list is owned; the exception is
+/// [`ListMut::pop`] on an empty slice, which has nothing to change.
+/// [`CowList::is_cloned`] reports whether the clone happened.
+///
+/// If `L` has a fixed capacity too small for the borrowed slice, the clone
+/// fails: [`ListMut::push`], [`ListMut::insert`] and [`ListMut::append`]
+/// report it as an error and the other mutating methods panic.
+#[derive(Debug, Clone)]
+pub struct CowList<'a, T, L> {
+    state: State<&'a [T], L>,
+    cloned: bool,
+}
+
+impl<'a, T, L> CowList<'a, T, L> {
+    pub fn borrowed(slice: &'a [T]) -> Self {
+        Self {
+            state: State::Borrowed(slice),
+            cloned: false,
+        }
+    }
+
+    pub fn owned(list: L) -> Self {
+        Self {
+            state: State::Owned(list),
+            cloned: false,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_owned(&self) -> bool {
+        matches!(self.state, State::Owned(_))
+    }
+
+    /// Whether the borrowed slice has been cloned into an owned list.
+    #[inline(always)]
+    pub fn is_cloned(&self) -> bool {
+        self.cloned
+    }
+}
+
+impl<'a, T, L> From<&'a [T]> for CowList<'a, T, L> {
+    fn from(slice: &'a [T]) -> Self {
+        Self::borrowed(slice)
+    }
+}
+
+impl<T, L: AsRef<[T]>> CowList<'_, T, L> {
+    /// The current contents, borrowed or owned.
+    pub fn view(&self) -> &[T] {
+        match &self.state {
+            State::Borrowed(slice) => slice,
+            State::Owned(list) => list.as_ref(),
+        }
+    }
+}
+
+impl<T: Clone, L: ListNew<T>> CowList<'_, T, L> {
+    /// The owned list, cloning the borrowed slice into it first if needed.
+    ///
+    /// Fails with [`Error::CapacityExceeded`], leaving the slice borrowed, if
+    /// `L` cannot hold it.
+    pub fn try_to_mut(&mut self) -> Result<&mut L, Error<T>> {
+        if let State::Borrowed(slice) = self.state {
+            let mut list = L::new();
+            for item in slice {
+                list.push(item.clone())
+                    .map_err(|_| Error::CapacityExceeded)?;
+            }
+            self.state = State::Owned(list);
+            self.cloned = true;
+        }
+        match &mut self.state {
+            State::Owned(list) => Ok(list),
+            State::Borrowed(_) => unreachable!("the slice was just cloned"),
+        }
+    }
+
+    /// The owned list, cloning the borrowed slice into it first if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `L` cannot hold the borrowed slice.
+    pub fn to_mut(&mut self) -> &mut L {
+        match self.try_to_mut() {
+            Ok(list) => list,
+            Err(_) => panic!("CowList backend cannot hold the borrowed slice"),
+        }
+    }
+
+    /// Unwraps the owned list, cloning the borrowed slice into it first if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `L` cannot hold the borrowed slice.
+    pub fn into_owned(mut self) -> L {
+        self.to_mut();
+        match self.state {
+            State::Owned(list) => list,
+            State::Borrowed(_) => unreachable!("the slice was just cloned"),
+        }
+    }
+}
+
+impl<T, L: AsRef<[T]>> Iterable for CowList<'_, T, L> {
+    type Item<'collection>
+        = &'collection T
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = core::slice::Iter<'collection, T>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.view().iter()
+    }
+}
+
+impl<T, L: AsRef<[T]>> Collection<T> for CowList<'_, T, L> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.view().len()
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.view().contains(other)
+    }
+}
+
+impl<T, L: ListNew<T> + AsRef<[T]>> CollectionMut<T> for CowList<'_, T, L> {
+    /// Replaces a borrowed slice with an empty owned list without cloning it.
+    fn clear(&mut self) {
+        match &mut self.state {
+            State::Borrowed(_) => self.state = State::Owned(L::new()),
+            State::Owned(list) => list.clear(),
+        }
+    }
+}
+
+impl<T: PartialEq + core::fmt::Debug, L: AsRef<[T]>> List<T> for CowList<'_, T, L> {
+    type Slice<'b>
+        = &'b [T]
+    where
+        T: 'b,
+        Self: 'b;
+
+    #[inline(always)]
+    fn as_slice(&self) -> Self::Slice<'_> {
+        self.view()
+    }
+
+    fn find_index(&self, other: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.view().iter().position(|x| x == other)
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Option<&T> {
+        self.view().first()
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Option<&T> {
+        self.view().last()
+    }
+
+    #[inline(always)]
+    fn get(&self, index: usize) -> Option<&T> {
+        self.view().get(index)
+    }
+
+    #[inline(always)]
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.view().binary_search(x)
+    }
+
+    #[inline(always)]
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        self.view().binary_search_by(f)
+    }
+
+    #[inline(always)]
+    fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.view().binary_search_by_key(b, f)
+    }
+
+    #[inline(always)]
+    fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.view().starts_with(needle)
+    }
+
+    #[inline(always)]
+    fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.view().ends_with(needle)
+    }
+}
+
+impl<T, L> ListNew<T> for CowList<'_, T, L>
+where
+    T: Clone + PartialEq + core::fmt::Debug,
+    L: ListNew<T> + AsRef<[T]>,
+{
+    /// Creates an empty owned list.
+    #[inline(always)]
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self::owned(L::new())
+    }
+}
+
+impl<T, L> ListMut<T> for CowList<'_, T, L>
+where
+    T: Clone + PartialEq + core::fmt::Debug,
+    L: ListNew<T> + AsRef<[T]>,
+{
+    type ElementMut<'b>
+        = L::ElementMut<'b>
+    where
+        T: 'b,
+        Self: 'b;
+
+    fn push(&mut self, item: T) -> Result<(), Error<T>> {
+        match self.try_to_mut() {
+            Ok(list) => list.push(item),
+            Err(_) => Err(Error::InsertFailed(item)),
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        match self.state {
+            State::Borrowed([]) => None,
+            _ => self.to_mut().pop(),
+        }
+    }
+
+    /// The capacity of the owned list, or the length of the borrowed slice.
+    fn capacity(&self) -> usize {
+        match &self.state {
+            State::Borrowed(slice) => slice.len(),
+            State::Owned(list) => list.capacity(),
+        }
+    }
+
+    #[inline(always)]
+    fn first_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.to_mut().first_mut()
+    }
+
+    #[inline(always)]
+    fn last_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.to_mut().last_mut()
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self, index: usize) -> Option<Self::ElementMut<'_>> {
+        self.to_mut().get_mut(index)
+    }
+
+    fn insert(&mut self, index: usize, element: T) -> Result<(), Error<T>> {
+        match self.try_to_mut() {
+            Ok(list) => list.insert(index, element),
+            Err(_) => Err(Error::InsertFailed(element)),
+        }
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, index: usize) -> T {
+        self.to_mut().remove(index)
+    }
+
+    #[inline(always)]
+    fn swap_remove(&mut self, index: usize) -> T {
+        self.to_mut().swap_remove(index)
+    }
+
+    #[inline(always)]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.to_mut().swap(a, b)
+    }
+
+    #[inline(always)]
+    fn reverse(&mut self) {
+        self.to_mut().reverse()
+    }
+
+    #[inline(always)]
+    fn truncate(&mut self, len: usize) {
+        self.to_mut().truncate(len)
+    }
+
+    #[inline(always)]
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.to_mut().retain(f)
+    }
+
+    #[inline(always)]
+    fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.to_mut().retain_mut(f)
+    }
+
+    #[inline(always)]
+    fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.to_mut().dedup()
+    }
+
+    #[inline(always)]
+    fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.to_mut().dedup_by(same_bucket)
+    }
+
+    #[inline(always)]
+    fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.to_mut().dedup_by_key(key)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.to_mut().fill(value)
+    }
+
+    #[inline(always)]
+    fn fill_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.to_mut().fill_with(f)
+    }
+
+    /// Clones both lists if they are borrowed, then moves the elements of
+    /// `other` into `self`.
+    fn append(&mut self, other: &mut Self) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        let other = other.try_to_mut()?;
+        self.try_to_mut()?.append(other)
+    }
+
+    /// Splits the owned list, returning the tail as a new owned list.
+    #[inline(always)]
+    fn split_off(&mut self, at: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self::owned(self.to_mut().split_off(at))
+    }
+}
+
+/// Iterator over a [`CowMap`], reading from whichever map is current.
+pub enum CowIter<B, O> {
+    Borrowed(B),
+    Owned(O),
+}
+
+impl<B: Iterator, O: Iterator<Item = B::Item>> Iterator for CowIter<B, O> {
+    type Item = B::Item;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<B::Item> {
+        match self {
+            CowIter::Borrowed(iter) => iter.next(),
+            CowIter::Owned(iter) => iter.next(),
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            CowIter::Borrowed(iter) => iter.size_hint(),
+            CowIter::Owned(iter) => iter.size_hint(),
+        }
+    }
+}
+
+/// Map that reads through a borrowed `B` until it is first changed.
+///
+/// Every [`MapMut`] method copies the borrowed entries into an owned `M`
+/// before doing its work, so after a mutating call the map is owned; the
+/// exceptions are [`MapMut::remove`] and [`GetMut::get_mut`] for a key the
+/// borrowed map does not hold, which have nothing to change.
+/// [`CowMap::is_cloned`] reports whether the copy happened.
+///
+/// If `M` has a fixed capacity too small for the borrowed entries, the copy
+/// fails: [`MapMut::try_insert`] reports it as an error and the other
+/// mutating methods panic.
+#[derive(Debug, Clone)]
+pub struct CowMap<'a, K, V, B, M> {
+    state: State<&'a B, M>,
+    cloned: bool,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<'a, K, V, B, M> CowMap<'a, K, V, B, M> {
+    pub fn borrowed(map: &'a B) -> Self {
+        Self {
+            state: State::Borrowed(map),
+            cloned: false,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn owned(map: M) -> Self {
+        Self {
+            state: State::Owned(map),
+            cloned: false,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_owned(&self) -> bool {
+        matches!(self.state, State::Owned(_))
+    }
+
+    /// Whether the borrowed map has been copied into an owned one.
+    #[inline(always)]
+    pub fn is_cloned(&self) -> bool {
+        self.cloned
+    }
+}
+
+impl<'a, K, V, B, M> CowMap<'a, K, V, B, M>
+where
+    K: Clone + 'a,
+    V: Clone + 'a,
+    B: Iterable<Item<'a> = (&'a K, &'a V)>,
+    M: MapMut<K, V> + Default,
+{
+    /// The owned map, copying the borrowed entries into it first if needed.
+    ///
+    /// Fails with [`Error::CapacityExceeded`], leaving the map borrowed, if
+    /// `M` cannot hold them.
+    pub fn try_to_mut(&mut self) -> Result<&mut M, Error<(K, V)>> {
+        if let State::Borrowed(borrowed) = self.state {
+            let mut map = M::default();
+            for (k, v) in borrowed.iter() {
+                map.try_insert(k.clone(), v.clone())
+                    .map_err(|_| Error::CapacityExceeded)?;
+            }
+            self.state = State::Owned(map);
+            self.cloned = true;
+        }
+        match &mut self.state {
+            State::Owned(map) => Ok(map),
+            State::Borrowed(_) => unreachable!("the map was just copied"),
+        }
+    }
+
+    /// The owned map, copying the borrowed entries into it first if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` cannot hold the borrowed entries.
+    pub fn to_mut(&mut self) -> &mut M {
+        match self.try_to_mut() {
+            Ok(map) => map,
+            Err(_) => panic!("CowMap backend cannot hold the borrowed entries"),
+        }
+    }
+
+    /// Unwraps the owned map, copying the borrowed entries into it first if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` cannot hold the borrowed entries.
+    pub fn into_owned(mut self) -> M {
+        self.to_mut();
+        match self.state {
+            State::Owned(map) => map,
+            State::Borrowed(_) => unreachable!("the map was just copied"),
+        }
+    }
+}
+
+type Entries<'c, K, V, C> =
+    core::iter::Zip<<C as Map<K, V>>::Keys<'c>, <C as Map<K, V>>::Values<'c>>;
+
+impl<K, V, B: Map<K, V>, M: Map<K, V>> Iterable for CowMap<'_, K, V, B, M> {
+    type Item<'collection>
+        = (&'collection K, &'collection V)
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = CowIter<Entries<'collection, K, V, B>, Entries<'collection, K, V, M>>
+    where
+        Self: 'collection;
+
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        match &self.state {
+            State::Borrowed(map) => CowIter::Borrowed(map.keys().zip(map.values())),
+            State::Owned(map) => CowIter::Owned(map.keys().zip(map.values())),
+        }
+    }
+}
+
+impl<K, V, B: Map<K, V>, M: Map<K, V>> Map<K, V> for CowMap<'_, K, V, B, M> {
+    type Keys<'key>
+        = CowIter<B::Keys<'key>, M::Keys<'key>>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = CowIter<B::Values<'value>, M::Values<'value>>
+    where
+        V: 'value,
+        Self: 'value;
+
+    fn len(&self) -> usize {
+        match &self.state {
+            State::Borrowed(map) => map.len(),
+            State::Owned(map) => map.len(),
+        }
+    }
+
+    fn get(&self, k: &K) -> Option<&V> {
+        match &self.state {
+            State::Borrowed(map) => map.get(k),
+            State::Owned(map) => map.get(k),
+        }
+    }
+
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        match &self.state {
+            State::Borrowed(map) => CowIter::Borrowed(map.keys()),
+            State::Owned(map) => CowIter::Owned(map.keys()),
+        }
+    }
+
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        match &self.state {
+            State::Borrowed(map) => CowIter::Borrowed(map.values()),
+            State::Owned(map) => CowIter::Owned(map.values()),
+        }
+    }
+
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)>
+    where
+        K: PartialEq,
+    {
+        match &self.state {
+            State::Borrowed(map) => map.get_key_value(k),
+            State::Owned(map) => map.get_key_value(k),
+        }
+    }
+}
+
+impl<'a, K, V, B, M> IterableMut for CowMap<'a, K, V, B, M>
+where
+    K: Clone + 'a,
+    V: Clone + 'a,
+    B: Map<K, V> + Iterable<Item<'a> = (&'a K, &'a V)>,
+    M: Map<K, V> + MapMut<K, V> + Default,
+{
+    type ItemMut<'collection>
+        = M::ItemMut<'collection>
+    where
+        Self: 'collection;
+
+    type IteratorMut<'collection>
+        = M::IteratorMut<'collection>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        self.to_mut().iter_mut()
+    }
+}
+
+impl<'a, K, V, B, M> MapMut<K, V> for CowMap<'a, K, V, B, M>
+where
+    K: Clone + 'a,
+    V: Clone + 'a,
+    B: Map<K, V> + Iterable<Item<'a> = (&'a K, &'a V)>,
+    M: Map<K, V> + MapMut<K, V> + Default,
+{
+    type ValuesMut<'value>
+        = M::ValuesMut<'value>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn values_mut<'c>(&'c mut self) -> Self::ValuesMut<'c> {
+        self.to_mut().values_mut()
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.to_mut().insert(key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        match self.state {
+            State::Borrowed(map) if map.get(key).is_none() => None,
+            _ => self.to_mut().remove(key),
+        }
+    }
+
+    /// The capacity of the owned map, or the length of the borrowed one.
+    fn capacity(&mut self) -> usize {
+        match &mut self.state {
+            State::Borrowed(map) => map.len(),
+            State::Owned(map) => map.capacity(),
+        }
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Error<(K, V)>> {
+        match self.try_to_mut() {
+            Ok(map) => map.try_insert(key, value),
+            Err(_) => Err(Error::InsertFailed((key, value))),
+        }
+    }
+}
+
+impl<'a, K, V, B, M> GetMut<K, V> for CowMap<'a, K, V, B, M>
+where
+    K: Clone + 'a,
+    V: Clone + 'a,
+    B: Map<K, V> + Iterable<Item<'a> = (&'a K, &'a V)>,
+    M: Map<K, V> + MapMut<K, V> + GetMut<K, V> + Default,
+{
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        match self.state {
+            State::Borrowed(map) if map.get(k).is_none() => None,
+            _ => self.to_mut().get_mut(k),
+        }
+    }
+}
@@ -21,3 +21,260 @@ pub trait IterableMut {
 
     fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c>;
 }
+
+/// Lazy adapters over the items of any [`Iterable`], including maps and
+/// sets, none of which allocate.
+///
+/// Lists that deref to slices have inherent `chunks`, `windows` and
+/// `dedup_by_key` methods of their own; call these through the trait, as in
+/// `IterableExt::chunks(&list, 2)`, when both are in scope.
+pub trait IterableExt: Iterable {
+    /// Splits the items into runs of `size`, the last of which may be
+    /// shorter. Each run re-reads the collection through a copy of its
+    /// iterator instead of buffering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn chunks<'c>(&'c self, size: usize) -> Chunks<Self::Iterator<'c>>
+    where
+        Self::Iterator<'c>: Clone,
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks {
+            iter: self.iter(),
+            size,
+        }
+    }
+
+    /// Every run of `N` consecutive items, buffered in an array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    fn windows<'c, const N: usize>(&'c self) -> Windows<Self::Iterator<'c>, N>
+    where
+        Self::Item<'c>: Clone,
+    {
+        assert!(N != 0, "window size must be non-zero");
+        Windows {
+            iter: self.iter(),
+            window: None,
+        }
+    }
+
+    /// Splits the items into runs that share the same `key`, yielding each
+    /// key with the run. Like [`IterableExt::chunks`], a run re-reads the
+    /// collection through a copy of its iterator.
+    fn group_by<'c, K, F>(&'c self, key: F) -> GroupBy<Self::Iterator<'c>, F>
+    where
+        Self::Iterator<'c>: Clone,
+        F: FnMut(&Self::Item<'c>) -> K,
+        K: PartialEq,
+    {
+        GroupBy {
+            iter: self.iter(),
+            key,
+        }
+    }
+
+    /// The items, skipping any whose `key` equals that of the item before.
+    fn dedup_by_key<'c, K, F>(&'c self, key: F) -> DedupByKey<Self::Iterator<'c>, F, K>
+    where
+        F: FnMut(&Self::Item<'c>) -> K,
+        K: PartialEq,
+    {
+        DedupByKey {
+            iter: self.iter(),
+            key,
+            last: None,
+        }
+    }
+
+    /// Combines the items of `self` and `other` pairwise with `f`, stopping
+    /// at the end of the shorter collection.
+    fn zip_with<'c, 'o, O, F, R>(
+        &'c self,
+        other: &'o O,
+        f: F,
+    ) -> ZipWith<Self::Iterator<'c>, O::Iterator<'o>, F>
+    where
+        O: Iterable + ?Sized,
+        F: FnMut(Self::Item<'c>, O::Item<'o>) -> R,
+    {
+        ZipWith {
+            a: self.iter(),
+            b: other.iter(),
+            f,
+        }
+    }
+
+    /// The item with the smallest `key`, the first one on ties.
+    fn min_by_key<'c, K, F>(&'c self, key: F) -> Option<Self::Item<'c>>
+    where
+        F: FnMut(&Self::Item<'c>) -> K,
+        K: Ord,
+    {
+        self.iter().min_by_key(key)
+    }
+
+    /// The item with the largest `key`, the last one on ties.
+    fn max_by_key<'c, K, F>(&'c self, key: F) -> Option<Self::Item<'c>>
+    where
+        F: FnMut(&Self::Item<'c>) -> K,
+        K: Ord,
+    {
+        self.iter().max_by_key(key)
+    }
+}
+
+impl<C: Iterable + ?Sized> IterableExt for C {}
+
+/// Iterator returned by [`IterableExt::chunks`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chunks<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator + Clone> Iterator for Chunks<I> {
+    type Item = core::iter::Take<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.iter.clone().take(self.size);
+        self.iter.next()?;
+        if self.size > 1 {
+            self.iter.nth(self.size - 2);
+        }
+        Some(chunk)
+    }
+}
+
+/// Iterator returned by [`IterableExt::windows`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Windows<I: Iterator, const N: usize> {
+    iter: I,
+    window: Option<[I::Item; N]>,
+}
+
+impl<I: Iterator, const N: usize> Iterator for Windows<I, N>
+where
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.window {
+            Some(window) => {
+                let item = self.iter.next()?;
+                window.rotate_left(1);
+                window[N - 1] = item;
+            }
+            None => {
+                let items = [(); N].map(|_| self.iter.next());
+                if items.iter().any(Option::is_none) {
+                    return None;
+                }
+                self.window = Some(items.map(Option::unwrap));
+            }
+        }
+        self.window.clone()
+    }
+}
+
+/// Iterator returned by [`IterableExt::group_by`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct GroupBy<I, F> {
+    iter: I,
+    key: F,
+}
+
+impl<I, F, K> Iterator for GroupBy<I, F>
+where
+    I: Iterator + Clone,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = (K, core::iter::Take<I>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.iter.clone();
+        let first = (self.key)(&self.iter.next()?);
+        let mut len = 1;
+        let mut ahead = self.iter.clone();
+        while let Some(item) = ahead.next() {
+            if (self.key)(&item) != first {
+                break;
+            }
+            self.iter = ahead.clone();
+            len += 1;
+        }
+        Some((first, start.take(len)))
+    }
+}
+
+/// Iterator returned by [`IterableExt::dedup_by_key`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupByKey<I, F, K> {
+    iter: I,
+    key: F,
+    last: Option<K>,
+}
+
+impl<I, F, K> Iterator for DedupByKey<I, F, K>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let item = self.iter.next()?;
+            let key = (self.key)(&item);
+            if self.last.as_ref() != Some(&key) {
+                self.last = Some(key);
+                return Some(item);
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`IterableExt::zip_with`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipWith<A, B, F> {
+    a: A,
+    b: B,
+    f: F,
+}
+
+impl<A, B, F, R> Iterator for ZipWith<A, B, F>
+where
+    A: Iterator,
+    B: Iterator,
+    F: FnMut(A::Item, B::Item) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        let a = self.a.next()?;
+        let b = self.b.next()?;
+        Some((self.f)(a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_min, a_max) = self.a.size_hint();
+        let (b_min, b_max) = self.b.size_hint();
+        let max = match (a_max, b_max) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        (a_min.min(b_min), max)
+    }
+}
@@ -3,17 +3,66 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "allocator-api")]
+mod allocator;
+mod bag;
+mod bounded;
 mod collection;
+mod cow;
 mod iter;
 mod list;
+mod macros;
 mod map;
+mod multimap;
+mod observed;
 mod set;
+mod view;
 
+#[cfg(feature = "allocator-api")]
+pub use allocator::{AllocError, AllocVec, Allocator, Global};
+pub use bag::{Bag, BagMut, GetMut};
+pub use bounded::Bounded;
 pub use collection::{Collection, CollectionMut};
-pub use iter::{Iterable, IterableMut};
-pub use list::{List, ListMut, ListResizable, ListSortable};
-pub use map::{Map, MapMut};
-pub use set::{Set, SetMut};
+pub use cow::{CowIter, CowList, CowMap};
+pub use iter::{Chunks, DedupByKey, GroupBy, Iterable, IterableExt, IterableMut, Windows, ZipWith};
+#[cfg(feature = "alloc")]
+pub use list::{
+    diff, kmerge, kmerge_by, lcs, BitList, Edit, HeapRingBuffer, KMerge, PList, Patch,
+};
+pub use list::{
+    merge_inplace, merge_inplace_by, merge_sorted_into, merge_sorted_into_by, BitArray, BitIter,
+    BitMut, BitSlice, ExtractIf, List, ListDrain, ListMut, ListNew, ListResizable, ListSliceMut,
+    ListSortable, Ring, RingBuffer, RingSlice, RingStorage, SortedList, SortedSet, WordList,
+};
+#[doc(hidden)]
+pub use macros::{__list, __map, __set, __try_list, __try_map, __try_set};
+#[cfg(feature = "alloc")]
+pub use map::BTreeBiMap;
+#[cfg(feature = "std")]
+pub use map::HashBiMap;
+#[cfg(feature = "heapless")]
+pub use map::FlatIntervalMap;
+#[cfg(feature = "alloc")]
+pub use map::{
+    FnvBuildHasher, FnvHasher, IntervalMap, LruMap, OrderedHashMap, PMap, SlotMap, TrieMap,
+};
+pub use map::{
+    ArrayLruMap, ArraySlotMap, ArrayTrieMap, BiMap, BidiMap, BidiMapMut, FlatMap, Gaps,
+    IntervalStorage, Key, Lru, LruIndex, LruSlot, Map, MapMut, Overlapping, Overwritten,
+    PrefixIter, RangeMap, ScanIndex, Slot, SlotArena, SlotStorage, Trie, TrieNode,
+};
+#[cfg(feature = "heapless")]
+pub use multimap::FixedMultiMap;
+pub use multimap::{MultiMap, MultiMapMut};
+#[cfg(feature = "alloc")]
+pub use observed::SpliceLog;
+pub use observed::{Callback, Change, Observed, Observer, Splice};
+#[cfg(feature = "heapless")]
+pub use set::FlatIntervalSet;
+#[cfg(feature = "alloc")]
+pub use set::{BitSet, IntervalSet, OrderedHashSet};
+pub use set::{BitStorage, FixedBitSet, RangeSet, Set, SetMut, WordSet};
+pub use view::{Filtered, KeySet, Mapped, Values};
 
 /// Error returned when a collection's capacity is exceeded.
 #[derive(Debug, PartialEq, Eq)]
@@ -0,0 +1,570 @@
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::ops::RangeBounds;
+
+use super::{
+    List, ListDrain, ListMut, ListNew, ListResizable, ListSliceMut, ListSortable,
+};
+use crate::allocator::{AllocVec, Allocator};
+use crate::list::{to_range, ExtractIf};
+use crate::{Collection, CollectionMut, Error, Iterable, IterableMut};
+
+// Growth goes through `try_reserve` first, so an exhausted arena surfaces as
+// an `Error` instead of aborting through `handle_alloc_error`.
+
+impl<T, A: Allocator> Iterable for AllocVec<T, A> {
+    type Item<'collection>
+        = &'collection T
+    where
+        Self: 'collection;
+    type Iterator<'collection>
+        = core::slice::Iter<'collection, T>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.as_slice().iter()
+    }
+}
+
+impl<T, A: Allocator> IterableMut for AllocVec<T, A> {
+    type ItemMut<'collection>
+        = &'collection mut T
+    where
+        Self: 'collection;
+    type IteratorMut<'collection>
+        = core::slice::IterMut<'collection, T>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T, A: Allocator> Collection<T> for AllocVec<T, A> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(other)
+    }
+}
+
+impl<T, A: Allocator> CollectionMut<T> for AllocVec<T, A> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: PartialEq + Debug, A: Allocator> List<T> for AllocVec<T, A> {
+    type Slice<'a>
+        = &'a [T]
+    where
+        T: 'a,
+        Self: 'a;
+
+    #[inline(always)]
+    fn as_slice(&self) -> Self::Slice<'_> {
+        AllocVec::as_slice(self)
+    }
+
+    fn find_index(&self, other: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|x| x == other)
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    #[inline(always)]
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    #[inline(always)]
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.as_slice().binary_search(x)
+    }
+
+    #[inline(always)]
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        self.as_slice().binary_search_by(f)
+    }
+
+    #[inline(always)]
+    fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.as_slice().binary_search_by_key(b, f)
+    }
+
+    #[inline(always)]
+    fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().starts_with(needle)
+    }
+
+    #[inline(always)]
+    fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().ends_with(needle)
+    }
+}
+
+/// Only allocators that can be made on demand, such as a zero-sized type
+/// pointing at a shared arena, can create a list out of nothing.
+impl<T, A> ListNew<T> for AllocVec<T, A>
+where
+    T: PartialEq + Debug,
+    A: Allocator + Clone + Default,
+{
+    #[inline(always)]
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        AllocVec::new_in(A::default())
+    }
+}
+
+/// [`ListMut::split_off`] hands the new list a copy of the allocator, so a
+/// borrowed arena such as `&Bump` works as well as a zero-sized handle.
+impl<T, A> ListMut<T> for AllocVec<T, A>
+where
+    T: PartialEq + Debug,
+    A: Allocator + Clone,
+{
+    type ElementMut<'a>
+        = &'a mut T
+    where
+        T: 'a,
+        Self: 'a;
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn push(&mut self, item: T) -> Result<(), Error<T>> {
+        if self.try_reserve(1).is_err() {
+            return Err(Error::InsertFailed(item));
+        }
+        self.push(item);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    #[inline(always)]
+    fn first_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.as_mut_slice().first_mut()
+    }
+
+    #[inline(always)]
+    fn last_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.as_mut_slice().last_mut()
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self, index: usize) -> Option<Self::ElementMut<'_>> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    fn insert(&mut self, index: usize, element: T) -> Result<(), Error<T>> {
+        if index > self.len() || self.try_reserve(1).is_err() {
+            return Err(Error::InsertFailed(element));
+        }
+        self.insert(index, element);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, index: usize) -> T {
+        self.remove(index)
+    }
+
+    #[inline(always)]
+    fn swap_remove(&mut self, index: usize) -> T {
+        self.swap_remove(index)
+    }
+
+    #[inline(always)]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b)
+    }
+
+    #[inline(always)]
+    fn reverse(&mut self) {
+        self.as_mut_slice().reverse()
+    }
+
+    #[inline(always)]
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
+    #[inline(always)]
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain(f)
+    }
+
+    #[inline(always)]
+    fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.retain_mut(f)
+    }
+
+    #[inline(always)]
+    fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup()
+    }
+
+    #[inline(always)]
+    fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.dedup_by(same_bucket)
+    }
+
+    #[inline(always)]
+    fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by_key(key)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.as_mut_slice().fill(value)
+    }
+
+    #[inline(always)]
+    fn fill_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.as_mut_slice().fill_with(f)
+    }
+
+    fn append(&mut self, other: &mut Self) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        if self.try_reserve(other.len()).is_err() {
+            return Err(Error::CapacityExceeded);
+        }
+        self.append(other);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn split_off(&mut self, at: usize) -> Self
+    where
+        T: Clone,
+    {
+        self.split_off(at)
+    }
+}
+
+impl<T, A> ListResizable<T> for AllocVec<T, A>
+where
+    T: PartialEq + Debug,
+    A: Allocator + Clone,
+{
+    fn resize(&mut self, new_len: usize, value: T) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        let additional = new_len.saturating_sub(self.len());
+        if self.try_reserve(additional).is_err() {
+            return Err(Error::CapacityExceeded);
+        }
+        self.resize(new_len, value);
+        Ok(())
+    }
+
+    fn resize_with<F>(&mut self, new_len: usize, f: F) -> Result<(), Error<T>>
+    where
+        F: FnMut() -> T,
+    {
+        let additional = new_len.saturating_sub(self.len());
+        if self.try_reserve(additional).is_err() {
+            return Err(Error::CapacityExceeded);
+        }
+        self.resize_with(new_len, f);
+        Ok(())
+    }
+
+    /// Reserving is only a hint, so an allocator that cannot satisfy it is
+    /// left alone rather than aborting.
+    #[inline(always)]
+    fn reserve(&mut self, additional: usize) {
+        let _ = self.try_reserve(additional);
+    }
+
+    #[inline(always)]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
+
+/// The stable sorts merge in place instead of using `slice::sort`, whose
+/// scratch buffer comes from the global allocator for all but short lists.
+impl<T, A> ListSortable<T> for AllocVec<T, A>
+where
+    T: PartialEq + Debug,
+    A: Allocator + Clone,
+{
+    #[inline(always)]
+    fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        sort_inplace_by(self.as_mut_slice(), T::cmp)
+    }
+
+    #[inline(always)]
+    fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort_inplace_by(self.as_mut_slice(), compare)
+    }
+
+    #[inline(always)]
+    fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        sort_inplace_by(self.as_mut_slice(), |a, b| f(a).cmp(&f(b)))
+    }
+
+    #[inline(always)]
+    fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort_unstable()
+    }
+
+    #[inline(always)]
+    fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.as_mut_slice().sort_unstable_by(compare)
+    }
+
+    #[inline(always)]
+    fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.as_mut_slice().sort_unstable_by_key(f)
+    }
+}
+
+/// Stable sort needing no extra memory: binary insertion sort for short
+/// runs, then rotation merges of doubling width, in O(n log² n).
+fn sort_inplace_by<T, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    const RUN: usize = 16;
+
+    for run in slice.chunks_mut(RUN) {
+        for i in 1..run.len() {
+            // Elements equal to the one being placed stay ahead of it.
+            let at = run[..i].partition_point(|x| compare(x, &run[i]) != Ordering::Greater);
+            run[at..=i].rotate_right(1);
+        }
+    }
+
+    let mut width = RUN;
+    while width < slice.len() {
+        let mut lo = 0;
+        while lo + width < slice.len() {
+            let hi = slice.len().min(lo + 2 * width);
+            merge_inplace_by(&mut slice[lo..hi], width, &mut compare);
+            lo = hi;
+        }
+        width *= 2;
+    }
+}
+
+/// Merges the sorted runs `slice[..mid]` and `slice[mid..]` the way
+/// [`merge_inplace_by`](crate::merge_inplace_by) does for lists.
+fn merge_inplace_by<T, F>(slice: &mut [T], mid: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    if mid == 0 || mid == len {
+        return;
+    }
+    if len == 2 {
+        if compare(&slice[1], &slice[0]) == Ordering::Less {
+            slice.swap(0, 1);
+        }
+        return;
+    }
+
+    let (left_cut, right_cut) = if mid > len - mid {
+        let left_cut = mid / 2;
+        // Elements of the right run equal to the pivot stay behind it.
+        let pivot = &slice[left_cut];
+        let right_cut =
+            mid + slice[mid..].partition_point(|x| compare(x, pivot) == Ordering::Less);
+        (left_cut, right_cut)
+    } else {
+        let right_cut = mid + (len - mid) / 2;
+        // Elements of the left run equal to the pivot stay ahead of it.
+        let pivot = &slice[right_cut];
+        let left_cut = slice[..mid].partition_point(|x| compare(pivot, x) != Ordering::Less);
+        (left_cut, right_cut)
+    };
+
+    slice[left_cut..right_cut].rotate_left(mid - left_cut);
+    let new_mid = left_cut + (right_cut - mid);
+    merge_inplace_by(&mut slice[..new_mid], left_cut, compare);
+    merge_inplace_by(&mut slice[new_mid..], right_cut - new_mid, compare);
+}
+
+impl<T, A> ListDrain<T> for AllocVec<T, A>
+where
+    T: PartialEq + Debug,
+    A: Allocator + Clone,
+{
+    type Drain<'a>
+        = allocator_api2::vec::Drain<'a, T, A>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type Splice<'a>
+        = allocator_api2::vec::Drain<'a, T, A>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type ExtractIf<'a, F>
+        = ExtractIf<'a, T, Self, F>
+    where
+        T: 'a,
+        Self: 'a,
+        F: FnMut(&mut T) -> bool;
+
+    #[inline(always)]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+
+    /// Inserts the replacement behind the range up front, then drains the
+    /// range, so the list is final as soon as this returns.
+    fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<Self::Splice<'_>, Error<T>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let range = to_range(range, self.len());
+        let replace_with = replace_with.into_iter();
+        if self.try_reserve(replace_with.len()).is_err() {
+            return Err(Error::CapacityExceeded);
+        }
+        drop(self.splice(range.end..range.end, replace_with));
+        Ok(self.drain(range))
+    }
+
+    fn extract_if<R, F>(&mut self, range: R, filter: F) -> Self::ExtractIf<'_, F>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool,
+    {
+        // SAFETY: these are the vector's own pointer and length accessors.
+        unsafe { ExtractIf::new(self, range, filter, AllocVec::as_mut_ptr, AllocVec::set_len) }
+    }
+
+    fn extend_from_slice(&mut self, other: &[T]) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        if self.try_reserve(other.len()).is_err() {
+            return Err(Error::CapacityExceeded);
+        }
+        self.extend_from_slice(other);
+        Ok(())
+    }
+
+    fn extend_from_within<R>(&mut self, src: R) -> Result<(), Error<T>>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let src = to_range(src, self.len());
+        if self.try_reserve(src.len()).is_err() {
+            return Err(Error::CapacityExceeded);
+        }
+        self.extend_from_within(src);
+        Ok(())
+    }
+}
+
+impl<T, A: Allocator> ListSliceMut<T> for AllocVec<T, A> {
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        AllocVec::as_mut_slice(self)
+    }
+}
@@ -0,0 +1,590 @@
+use core::fmt::Debug;
+use core::ops::{Deref, DerefMut};
+
+use super::{List, ListMut, ListNew};
+use crate::{BitStorage, Collection, CollectionMut, Error, Iterable};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+#[inline(always)]
+fn words_for(bits: usize) -> usize {
+    bits / WORD_BITS + usize::from(bits % WORD_BITS != 0)
+}
+
+#[inline(always)]
+fn bit_ref(bit: bool) -> &'static bool {
+    if bit {
+        &true
+    } else {
+        &false
+    }
+}
+
+#[inline(always)]
+fn read(words: &[u64], index: usize) -> bool {
+    words[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+}
+
+/// List of `bool`s packed one per bit.
+///
+/// Since single bits cannot be borrowed, [`List::get`] hands out references
+/// to static `true`/`false` values and [`ListMut::get_mut`] returns a
+/// [`BitMut`] proxy that writes back when dropped; [`ListMut::set`] avoids
+/// the proxy altogether.
+///
+/// Use [`BitList`] for a list that grows to fit, or [`BitArray`] for one
+/// holding up to `64 * WORDS` bits without an allocator. Stable Rust cannot
+/// derive a word count from a bit count in a const generic, so the fixed
+/// variant is sized in words, like [`FixedBitSet`](crate::FixedBitSet).
+#[derive(Clone)]
+pub struct WordList<S> {
+    words: S,
+    len: usize,
+}
+
+/// Growable [`WordList`].
+#[cfg(feature = "alloc")]
+pub type BitList = WordList<alloc::vec::Vec<u64>>;
+
+/// [`WordList`] holding up to `64 * WORDS` bits.
+pub type BitArray<const WORDS: usize> = WordList<[u64; WORDS]>;
+
+#[cfg(feature = "alloc")]
+impl BitList {
+    /// Creates a list with room for `bits` bits before it grows.
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: alloc::vec![0; words_for(bits)],
+            len: 0,
+        }
+    }
+}
+
+impl<S: BitStorage> WordList<S> {
+    pub fn new() -> Self {
+        Self {
+            words: S::empty(),
+            len: 0,
+        }
+    }
+
+    /// A read-only view of the bits.
+    #[inline(always)]
+    pub fn as_bits(&self) -> BitSlice<'_> {
+        BitSlice {
+            words: self.words.words(),
+            len: self.len,
+        }
+    }
+
+    /// The raw words, least significant bit first. Bits past the end of the
+    /// list are always zero.
+    #[inline(always)]
+    pub fn as_words(&self) -> &[u64] {
+        self.words.words()
+    }
+
+    /// Number of `true` bits.
+    pub fn count_ones(&self) -> usize {
+        self.as_words()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    #[inline(always)]
+    fn bit(&self, index: usize) -> bool {
+        read(self.words.words(), index)
+    }
+
+    /// Writes a bit within the current storage.
+    #[inline(always)]
+    fn write(&mut self, index: usize, bit: bool) {
+        let word = &mut self.words.words_mut()[index / WORD_BITS];
+        let mask = 1 << (index % WORD_BITS);
+        if bit {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    fn proxy(&mut self, index: usize) -> Option<BitMut<'_>> {
+        if index >= self.len {
+            return None;
+        }
+        let word = &mut self.words.words_mut()[index / WORD_BITS];
+        let mask = 1 << (index % WORD_BITS);
+        Some(BitMut {
+            value: *word & mask != 0,
+            word,
+            mask,
+        })
+    }
+}
+
+impl<S: BitStorage> Default for WordList<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: BitStorage> Debug for WordList<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_bits().fmt(f)
+    }
+}
+
+impl<S: BitStorage, O: BitStorage> PartialEq<WordList<O>> for WordList<S> {
+    fn eq(&self, other: &WordList<O>) -> bool {
+        self.as_bits() == other.as_bits()
+    }
+}
+
+impl<S: BitStorage> Eq for WordList<S> {}
+
+/// Mutable proxy for one bit of a [`WordList`], written back on drop.
+pub struct BitMut<'a> {
+    word: &'a mut u64,
+    mask: u64,
+    value: bool,
+}
+
+impl Deref for BitMut<'_> {
+    type Target = bool;
+
+    #[inline(always)]
+    fn deref(&self) -> &bool {
+        &self.value
+    }
+}
+
+impl DerefMut for BitMut<'_> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut bool {
+        &mut self.value
+    }
+}
+
+impl Drop for BitMut<'_> {
+    fn drop(&mut self) {
+        if self.value {
+            *self.word |= self.mask;
+        } else {
+            *self.word &= !self.mask;
+        }
+    }
+}
+
+/// Read-only view of packed bits, the [`List::Slice`] of a [`WordList`].
+#[derive(Clone, Copy)]
+pub struct BitSlice<'a> {
+    words: &'a [u64],
+    len: usize,
+}
+
+impl<'a> BitSlice<'a> {
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<bool> {
+        (index < self.len).then(|| read(self.words, index))
+    }
+
+    pub fn iter(&self) -> BitIter<'a> {
+        BitIter {
+            words: self.words,
+            front: 0,
+            back: self.len,
+        }
+    }
+}
+
+impl Debug for BitSlice<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for BitSlice<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl PartialEq<&[bool]> for BitSlice<'_> {
+    fn eq(&self, other: &&[bool]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter().copied())
+    }
+}
+
+/// Iterator over the bits of a [`WordList`], front to back.
+#[derive(Debug, Clone)]
+pub struct BitIter<'a> {
+    words: &'a [u64],
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for BitIter<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(read(self.words, self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for BitIter<'_> {
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(read(self.words, self.back))
+    }
+}
+
+impl ExactSizeIterator for BitIter<'_> {}
+
+impl<S: BitStorage> Iterable for WordList<S> {
+    type Item<'collection>
+        = bool
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = BitIter<'collection>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.as_bits().iter()
+    }
+}
+
+impl<S: BitStorage> Collection<bool> for WordList<S> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn contains(&self, other: &bool) -> bool {
+        self.iter().any(|bit| bit == *other)
+    }
+}
+
+impl<S: BitStorage> CollectionMut<bool> for WordList<S> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.truncate(0);
+    }
+}
+
+impl<S: BitStorage> List<bool> for WordList<S> {
+    type Slice<'a>
+        = BitSlice<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn as_slice(&self) -> Self::Slice<'_> {
+        self.as_bits()
+    }
+
+    fn find_index(&self, other: &bool) -> Option<usize> {
+        self.iter().position(|bit| bit == *other)
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Option<&bool> {
+        self.get(0)
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Option<&bool> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// Returns a reference to a static `true` or `false`, since a packed bit
+    /// cannot be borrowed in place.
+    fn get(&self, index: usize) -> Option<&bool> {
+        (index < self.len).then(|| bit_ref(self.bit(index)))
+    }
+
+    #[inline(always)]
+    fn binary_search(&self, x: &bool) -> Result<usize, usize> {
+        self.binary_search_by(|bit| bit.cmp(x))
+    }
+
+    fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&bool) -> core::cmp::Ordering,
+    {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match f(&self.bit(mid)) {
+                core::cmp::Ordering::Less => low = mid + 1,
+                core::cmp::Ordering::Greater => high = mid,
+                core::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    #[inline(always)]
+    fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&bool) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|bit| f(bit).cmp(b))
+    }
+
+    fn starts_with(&self, needle: &[bool]) -> bool {
+        needle.len() <= self.len && self.iter().zip(needle).all(|(bit, n)| bit == *n)
+    }
+
+    fn ends_with(&self, needle: &[bool]) -> bool {
+        needle.len() <= self.len
+            && self
+                .iter()
+                .skip(self.len - needle.len())
+                .zip(needle)
+                .all(|(bit, n)| bit == *n)
+    }
+}
+
+impl<S: BitStorage> ListNew<bool> for WordList<S> {
+    #[inline(always)]
+    fn new() -> Self {
+        WordList::new()
+    }
+}
+
+impl<S: BitStorage> ListMut<bool> for WordList<S> {
+    type ElementMut<'a>
+        = BitMut<'a>
+    where
+        Self: 'a;
+
+    fn push(&mut self, item: bool) -> Result<(), Error<bool>> {
+        if !self.words.grow(words_for(self.len + 1)) {
+            return Err(Error::InsertFailed(item));
+        }
+        self.write(self.len, item);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<bool> {
+        self.len = self.len.checked_sub(1)?;
+        let bit = self.bit(self.len);
+        self.write(self.len, false);
+        Some(bit)
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.words.words().len() * WORD_BITS
+    }
+
+    #[inline(always)]
+    fn first_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.proxy(0)
+    }
+
+    #[inline(always)]
+    fn last_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.proxy(self.len.checked_sub(1)?)
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self, index: usize) -> Option<Self::ElementMut<'_>> {
+        self.proxy(index)
+    }
+
+    fn set(&mut self, index: usize, value: bool) -> Result<bool, Error<bool>> {
+        if index >= self.len {
+            return Err(Error::InsertFailed(value));
+        }
+        let old = self.bit(index);
+        self.write(index, value);
+        Ok(old)
+    }
+
+    fn insert(&mut self, index: usize, element: bool) -> Result<(), Error<bool>> {
+        if index > self.len || !self.words.grow(words_for(self.len + 1)) {
+            return Err(Error::InsertFailed(element));
+        }
+        for i in (index..self.len).rev() {
+            self.write(i + 1, self.bit(i));
+        }
+        self.write(index, element);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn remove(&mut self, index: usize) -> bool {
+        assert!(
+            index < self.len,
+            "removal index (is {index}) should be < len (is {})",
+            self.len
+        );
+        let bit = self.bit(index);
+        for i in index + 1..self.len {
+            self.write(i - 1, self.bit(i));
+        }
+        self.pop();
+        bit
+    }
+
+    fn swap_remove(&mut self, index: usize) -> bool {
+        assert!(
+            index < self.len,
+            "swap_remove index (is {index}) should be < len (is {})",
+            self.len
+        );
+        let bit = self.bit(index);
+        let last = self.bit(self.len - 1);
+        self.write(index, last);
+        self.pop();
+        bit
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len && b < self.len, "swap index out of bounds");
+        let (bit_a, bit_b) = (self.bit(a), self.bit(b));
+        self.write(a, bit_b);
+        self.write(b, bit_a);
+    }
+
+    fn reverse(&mut self) {
+        for i in 0..self.len / 2 {
+            self.swap(i, self.len - 1 - i);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop();
+        }
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&bool) -> bool,
+    {
+        self.retain_mut(|bit| f(bit))
+    }
+
+    fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut bool) -> bool,
+    {
+        let mut kept = 0;
+        for i in 0..self.len {
+            let mut bit = self.bit(i);
+            if f(&mut bit) {
+                self.write(kept, bit);
+                kept += 1;
+            }
+        }
+        self.truncate(kept);
+    }
+
+    #[inline(always)]
+    fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut bool, &mut bool) -> bool,
+    {
+        if self.len <= 1 {
+            return;
+        }
+        let mut kept = 1;
+        for i in 1..self.len {
+            let mut bit = self.bit(i);
+            let mut prev = self.bit(kept - 1);
+            let duplicate = same_bucket(&mut bit, &mut prev);
+            self.write(kept - 1, prev);
+            if !duplicate {
+                self.write(kept, bit);
+                kept += 1;
+            }
+        }
+        self.truncate(kept);
+    }
+
+    fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut bool) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    fn fill(&mut self, value: bool) {
+        self.fill_with(|| value)
+    }
+
+    fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> bool,
+    {
+        for i in 0..self.len {
+            self.write(i, f());
+        }
+    }
+
+    /// Moves all of `other`'s bits onto the end of `self`, or fails with
+    /// [`Error::CapacityExceeded`] and leaves both untouched if they do not
+    /// fit.
+    fn append(&mut self, other: &mut Self) -> Result<(), Error<bool>> {
+        if !self.words.grow(words_for(self.len + other.len)) {
+            return Err(Error::CapacityExceeded);
+        }
+        for i in 0..other.len {
+            self.write(self.len + i, other.bit(i));
+        }
+        self.len += other.len;
+        other.clear();
+        Ok(())
+    }
+
+    fn split_off(&mut self, at: usize) -> Self {
+        assert!(
+            at <= self.len,
+            "`at` split index (is {at}) should be <= len (is {})",
+            self.len
+        );
+        let mut tail = Self::new();
+        tail.words.grow(words_for(self.len - at));
+        for i in at..self.len {
+            tail.write(i - at, self.bit(i));
+        }
+        tail.len = self.len - at;
+        self.truncate(at);
+        tail
+    }
+}
@@ -0,0 +1,352 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{Error, List, ListMut};
+
+/// One run of a [`Patch`], read against the source list from front to back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Edit<T> {
+    /// Keep the next `n` elements of the source.
+    Keep(usize),
+
+    /// Drop the next `n` elements of the source.
+    Delete(usize),
+
+    /// Insert these elements before the next element of the source.
+    Insert(Vec<T>),
+}
+
+/// Edit script turning one list into another, as computed by [`diff`].
+///
+/// Its `Keep` and `Delete` runs always add up to the source length.
+/// Deserializing checks this, so a patch from an untrusted source cannot make
+/// [`Patch::apply_to`] index out of bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawPatch<T>")
+)]
+pub struct Patch<T> {
+    source_len: usize,
+    edits: Vec<Edit<T>>,
+}
+
+/// A [`Patch`] as it is serialized, before its runs have been checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawPatch<T> {
+    source_len: usize,
+    edits: Vec<Edit<T>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RawPatch<T>> for Patch<T> {
+    type Error = &'static str;
+
+    fn try_from(raw: RawPatch<T>) -> Result<Self, Self::Error> {
+        let mut read = 0usize;
+        let mut written = 0usize;
+        for edit in &raw.edits {
+            let (r, w) = match edit {
+                Edit::Keep(n) => (*n, *n),
+                Edit::Delete(n) => (*n, 0),
+                Edit::Insert(items) => (0, items.len()),
+            };
+            read = read.checked_add(r).ok_or("patch runs overflow")?;
+            written = written.checked_add(w).ok_or("patch runs overflow")?;
+        }
+        if read != raw.source_len {
+            return Err("patch runs do not add up to the source length");
+        }
+        Ok(Self {
+            source_len: raw.source_len,
+            edits: raw.edits,
+        })
+    }
+}
+
+impl<T> Patch<T> {
+    pub fn edits(&self) -> &[Edit<T>] {
+        &self.edits
+    }
+
+    /// The length of the list the patch was computed from.
+    pub fn source_len(&self) -> usize {
+        self.source_len
+    }
+
+    /// The length of the list after the patch is applied.
+    pub fn target_len(&self) -> usize {
+        self.edits
+            .iter()
+            .map(|edit| match edit {
+                Edit::Keep(n) => *n,
+                Edit::Delete(_) => 0,
+                Edit::Insert(items) => items.len(),
+            })
+            .sum()
+    }
+
+    /// Whether applying the patch leaves the list unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.edits.iter().all(|edit| matches!(edit, Edit::Keep(_)))
+    }
+
+    /// Applies the patch to `list`, which must equal the source list.
+    ///
+    /// All deletions are applied before any insertion, so a bounded list
+    /// only needs room for the larger of the source and the target. If an
+    /// insertion still does not fit, the element is returned in
+    /// [`Error::InsertFailed`] and `list` is left with every deletion and
+    /// the insertions before it applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `list` does not have the length of the source list.
+    pub fn apply_to<L: ListMut<T>>(&self, list: &mut L) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        assert_eq!(
+            list.len(),
+            self.source_len,
+            "patch computed for a list of a different length"
+        );
+
+        let mut index = self.source_len;
+        for edit in self.edits.iter().rev() {
+            match edit {
+                Edit::Keep(n) => index -= n,
+                Edit::Delete(n) => {
+                    index -= n;
+                    for i in (index..index + n).rev() {
+                        list.remove(i);
+                    }
+                }
+                Edit::Insert(_) => {}
+            }
+        }
+
+        for edit in &self.edits {
+            match edit {
+                Edit::Keep(n) => index += n,
+                Edit::Delete(_) => {}
+                Edit::Insert(items) => {
+                    for item in items {
+                        list.insert(index, item.clone())?;
+                        index += 1;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Step {
+    Keep(usize, usize),
+    Delete,
+    Insert(usize),
+}
+
+/// Shortest edit script from `a` to `b`, one step per element, using the
+/// linear-space refinement of Myers' O((N + M) D) algorithm: rather than
+/// remembering every round to walk back through, it finds the middle of the
+/// path and recurses on both halves.
+fn myers<T, A, B>(a: &A, b: &B) -> Vec<Step>
+where
+    T: PartialEq,
+    A: List<T>,
+    B: List<T>,
+{
+    let same = |x: usize, y: usize| a.get(x) == b.get(y);
+    let max_d = (a.len() + b.len() + 1) / 2 + 1;
+    let mut search = Search {
+        same,
+        forward: Diagonals::new(max_d),
+        backward: Diagonals::new(max_d),
+        steps: Vec::new(),
+    };
+    search.conquer(0..a.len(), 0..b.len());
+    search.steps
+}
+
+/// Furthest-reaching x on each diagonal `k = x - y`, for `-d..=d`.
+struct Diagonals {
+    offset: isize,
+    v: Vec<usize>,
+}
+
+impl Diagonals {
+    fn new(max_d: usize) -> Self {
+        Self {
+            offset: max_d as isize,
+            v: alloc::vec![0; 2 * max_d + 2],
+        }
+    }
+}
+
+impl core::ops::Index<isize> for Diagonals {
+    type Output = usize;
+
+    fn index(&self, k: isize) -> &usize {
+        &self.v[(k + self.offset) as usize]
+    }
+}
+
+impl core::ops::IndexMut<isize> for Diagonals {
+    fn index_mut(&mut self, k: isize) -> &mut usize {
+        &mut self.v[(k + self.offset) as usize]
+    }
+}
+
+struct Search<F> {
+    same: F,
+    forward: Diagonals,
+    backward: Diagonals,
+    steps: Vec<Step>,
+}
+
+impl<F: Fn(usize, usize) -> bool> Search<F> {
+    /// Appends the steps turning `a[x]` into `b[y]`.
+    fn conquer(&mut self, mut x: Range<usize>, mut y: Range<usize>) {
+        let prefix = self.common_prefix(x.clone(), y.clone());
+        for i in 0..prefix {
+            self.steps.push(Step::Keep(x.start + i, y.start + i));
+        }
+        x.start += prefix;
+        y.start += prefix;
+        let suffix = self.common_suffix(x.clone(), y.clone());
+        x.end -= suffix;
+        y.end -= suffix;
+
+        if x.is_empty() {
+            self.steps.extend(y.clone().map(Step::Insert));
+        } else if y.is_empty() {
+            self.steps.extend(x.clone().map(|_| Step::Delete));
+        } else {
+            let (mx, my) = self.middle_snake(x.clone(), y.clone());
+            self.conquer(x.start..mx, y.start..my);
+            self.conquer(mx..x.end, my..y.end);
+        }
+
+        for i in 0..suffix {
+            self.steps.push(Step::Keep(x.end + i, y.end + i));
+        }
+    }
+
+    /// A point on a shortest path through `a[x]` and `b[y]`, both non-empty
+    /// and differing in their first and last elements, that splits the
+    /// path's edits between its two halves.
+    fn middle_snake(&mut self, x: Range<usize>, y: Range<usize>) -> (usize, usize) {
+        let (n, m) = (x.len(), y.len());
+        let delta = n as isize - m as isize;
+        let odd = delta & 1 == 1;
+        self.forward[1] = 0;
+        self.backward[1] = 0;
+
+        let max_d = ((n + m + 1) / 2 + 1) as isize;
+        for d in 0..max_d {
+            for k in (-d..=d).rev().step_by(2) {
+                let v = &self.forward;
+                let x0 = if k == -d || (k != d && v[k - 1] < v[k + 1]) {
+                    v[k + 1]
+                } else {
+                    v[k - 1] + 1
+                };
+                let y0 = (x0 as isize - k) as usize;
+                let snake = if x0 < n && y0 < m {
+                    self.common_prefix(x.start + x0..x.end, y.start + y0..y.end)
+                } else {
+                    0
+                };
+                self.forward[k] = x0 + snake;
+                if odd && (k - delta).abs() < d && self.forward[k] + self.backward[delta - k] >= n {
+                    return (x.start + x0, y.start + y0);
+                }
+            }
+
+            for k in (-d..=d).rev().step_by(2) {
+                let v = &self.backward;
+                let x0 = if k == -d || (k != d && v[k - 1] < v[k + 1]) {
+                    v[k + 1]
+                } else {
+                    v[k - 1] + 1
+                };
+                let y0 = (x0 as isize - k) as usize;
+                let snake = if x0 < n && y0 < m {
+                    self.common_suffix(x.start..x.end - x0, y.start..y.end - y0)
+                } else {
+                    0
+                };
+                self.backward[k] = x0 + snake;
+                if !odd && (k - delta).abs() <= d && self.backward[k] + self.forward[delta - k] >= n
+                {
+                    return (x.end - x0 - snake, y.end - y0 - snake);
+                }
+            }
+        }
+        unreachable!("the forward and backward searches always meet")
+    }
+
+    fn common_prefix(&self, x: Range<usize>, y: Range<usize>) -> usize {
+        x.zip(y).take_while(|&(i, j)| (self.same)(i, j)).count()
+    }
+
+    fn common_suffix(&self, x: Range<usize>, y: Range<usize>) -> usize {
+        x.rev()
+            .zip(y.rev())
+            .take_while(|&(i, j)| (self.same)(i, j))
+            .count()
+    }
+}
+
+/// Computes the shortest [`Patch`] turning `a` into `b`.
+///
+/// The lists may use different backends, such as a `Vec` and a
+/// `heapless::Vec`.
+pub fn diff<T, A, B>(a: &A, b: &B) -> Patch<T>
+where
+    T: PartialEq + Clone,
+    A: List<T>,
+    B: List<T>,
+{
+    let mut edits: Vec<Edit<T>> = Vec::new();
+    for step in myers(a, b) {
+        match (step, edits.last_mut()) {
+            (Step::Keep(..), Some(Edit::Keep(n))) | (Step::Delete, Some(Edit::Delete(n))) => {
+                *n += 1
+            }
+            (Step::Insert(y), Some(Edit::Insert(items))) => items.push(b.get(y).unwrap().clone()),
+            (Step::Keep(..), _) => edits.push(Edit::Keep(1)),
+            (Step::Delete, _) => edits.push(Edit::Delete(1)),
+            (Step::Insert(y), _) => {
+                edits.push(Edit::Insert(alloc::vec![b.get(y).unwrap().clone()]))
+            }
+        }
+    }
+    Patch {
+        source_len: a.len(),
+        edits,
+    }
+}
+
+/// A longest common subsequence of `a` and `b`, as pairs of indices into
+/// `a` and `b` in increasing order.
+pub fn lcs<T, A, B>(a: &A, b: &B) -> Vec<(usize, usize)>
+where
+    T: PartialEq,
+    A: List<T>,
+    B: List<T>,
+{
+    myers(a, b)
+        .into_iter()
+        .filter_map(|step| match step {
+            Step::Keep(x, y) => Some((x, y)),
+            _ => None,
+        })
+        .collect()
+}
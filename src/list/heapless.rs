@@ -1,11 +1,16 @@
 use core::fmt::Debug;
+use core::ops::RangeBounds;
 
-use super::{List, ListMut};
+use super::{List, ListDrain, ListMut, ListNew, ListSliceMut};
 use crate::{Collection, CollectionMut, Error, Iterable, IterableMut};
 
 mod inner_vec {
+    use core::ops::RangeBounds;
     use core::slice::{Iter, IterMut};
 
+    use heapless::vec::{Drain, IntoIter};
+
+    use crate::list::{to_range, ExtractIf};
     use crate::Error;
 
     #[inline(always)]
@@ -292,6 +297,103 @@ mod inner_vec {
     pub(crate) fn new<T, const N: usize>() -> heapless::Vec<T, N> {
         heapless::Vec::new()
     }
+
+    #[inline(always)]
+    pub(crate) fn as_mut_slice<T, const N: usize>(vec: &mut heapless::Vec<T, N>) -> &mut [T] {
+        vec.as_mut_slice()
+    }
+
+    #[inline(always)]
+    pub(crate) fn drain<T, R, const N: usize>(
+        vec: &mut heapless::Vec<T, N>,
+        range: R,
+    ) -> Drain<'_, T, usize>
+    where
+        R: RangeBounds<usize>,
+    {
+        vec.drain(range)
+    }
+
+    /// Collects the replacement before touching the list, so one that does
+    /// not fit leaves it unchanged. The replacement is then swapped into
+    /// the range, which leaves the buffer holding the removed elements.
+    pub(crate) fn splice<T, R, I, const N: usize>(
+        vec: &mut heapless::Vec<T, N>,
+        range: R,
+        replace_with: I,
+    ) -> Result<IntoIter<T, N, usize>, Error<T>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let range = to_range(range, vec.len());
+        let mut buffer = heapless::Vec::<T, N>::new();
+        for item in replace_with {
+            buffer.push(item).map_err(|_| Error::CapacityExceeded)?;
+        }
+        if vec.len() - range.len() + buffer.len() > N {
+            return Err(Error::CapacityExceeded);
+        }
+
+        let swapped = range.len().min(buffer.len());
+        vec[range.start..range.start + swapped].swap_with_slice(&mut buffer[..swapped]);
+        if buffer.len() > range.len() {
+            let grown = buffer.len() - range.len();
+            vec.extend(buffer.drain(range.len()..));
+            vec[range.end..].rotate_right(grown);
+        } else {
+            buffer.extend(vec.drain(range.start + swapped..range.end));
+        }
+        Ok(buffer.into_iter())
+    }
+
+    #[inline(always)]
+    pub(crate) fn extract_if<T, R, F, const N: usize>(
+        vec: &mut heapless::Vec<T, N>,
+        range: R,
+        filter: F,
+    ) -> ExtractIf<'_, T, heapless::Vec<T, N>, F>
+    where
+        R: RangeBounds<usize>,
+    {
+        // SAFETY: these are the vector's own pointer and length accessors.
+        unsafe {
+            ExtractIf::new(
+                vec,
+                range,
+                filter,
+                heapless::Vec::as_mut_ptr,
+                heapless::Vec::set_len,
+            )
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn extend_from_slice<T: Clone, const N: usize>(
+        vec: &mut heapless::Vec<T, N>,
+        other: &[T],
+    ) -> Result<(), Error<T>> {
+        vec.extend_from_slice(other)
+            .map_err(|_| Error::CapacityExceeded)
+    }
+
+    pub(crate) fn extend_from_within<T: Clone, R, const N: usize>(
+        vec: &mut heapless::Vec<T, N>,
+        src: R,
+    ) -> Result<(), Error<T>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let src = to_range(src, vec.len());
+        if vec.len() + src.len() > N {
+            return Err(Error::CapacityExceeded);
+        }
+        for index in src {
+            let item = vec[index].clone();
+            vec.push(item).map_err(Error::InsertFailed)?;
+        }
+        Ok(())
+    }
 }
 
 impl<T, const N: usize> Iterable for heapless::Vec<T, N> {
@@ -420,7 +522,7 @@ impl<T: PartialEq + Debug, const N: usize> List<T> for heapless::Vec<T, N> {
     }
 }
 
-impl<T: PartialEq + Debug, const N: usize> ListMut<T> for heapless::Vec<T, N> {
+impl<T: PartialEq + Debug, const N: usize> ListNew<T> for heapless::Vec<T, N> {
     #[inline(always)]
     fn new() -> Self
     where
@@ -428,6 +530,14 @@ impl<T: PartialEq + Debug, const N: usize> ListMut<T> for heapless::Vec<T, N> {
     {
         inner_vec::new()
     }
+}
+
+impl<T: PartialEq + Debug, const N: usize> ListMut<T> for heapless::Vec<T, N> {
+    type ElementMut<'a>
+        = &'a mut T
+    where
+        T: 'a,
+        Self: 'a;
 
     #[inline(always)]
     fn capacity(&self) -> usize {
@@ -445,17 +555,17 @@ impl<T: PartialEq + Debug, const N: usize> ListMut<T> for heapless::Vec<T, N> {
     }
 
     #[inline(always)]
-    fn first_mut(&mut self) -> Option<&mut T> {
+    fn first_mut(&mut self) -> Option<Self::ElementMut<'_>> {
         inner_vec::first_mut(self)
     }
 
     #[inline(always)]
-    fn last_mut(&mut self) -> Option<&mut T> {
+    fn last_mut(&mut self) -> Option<Self::ElementMut<'_>> {
         inner_vec::last_mut(self)
     }
 
     #[inline(always)]
-    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+    fn get_mut(&mut self, index: usize) -> Option<Self::ElementMut<'_>> {
         inner_vec::get_mut(self, index)
     }
 
@@ -562,3 +672,75 @@ impl<T: PartialEq + Debug, const N: usize> ListMut<T> for heapless::Vec<T, N> {
         inner_vec::split_off(self, at)
     }
 }
+
+impl<T: PartialEq + Debug, const N: usize> ListDrain<T> for heapless::Vec<T, N> {
+    type Drain<'a>
+        = heapless::vec::Drain<'a, T, usize>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type Splice<'a>
+        = heapless::vec::IntoIter<T, N, usize>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type ExtractIf<'a, F>
+        = crate::list::ExtractIf<'a, T, Self, F>
+    where
+        T: 'a,
+        Self: 'a,
+        F: FnMut(&mut T) -> bool;
+
+    #[inline(always)]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        inner_vec::drain(self, range)
+    }
+
+    #[inline(always)]
+    fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<Self::Splice<'_>, Error<T>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        inner_vec::splice(self, range, replace_with)
+    }
+
+    #[inline(always)]
+    fn extract_if<R, F>(&mut self, range: R, filter: F) -> Self::ExtractIf<'_, F>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool,
+    {
+        inner_vec::extract_if(self, range, filter)
+    }
+
+    #[inline(always)]
+    fn extend_from_slice(&mut self, other: &[T]) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        inner_vec::extend_from_slice(self, other)
+    }
+
+    #[inline(always)]
+    fn extend_from_within<R>(&mut self, src: R) -> Result<(), Error<T>>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        inner_vec::extend_from_within(self, src)
+    }
+}
+
+impl<T, const N: usize> ListSliceMut<T> for heapless::Vec<T, N> {
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        inner_vec::as_mut_slice(self)
+    }
+}
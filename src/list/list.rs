@@ -1,7 +1,9 @@
 use crate::Collection;
 
 pub trait List<T>: Collection<T> {
-    type Slice<'a>: AsRef<[T]> + PartialEq<&'a [T]> + core::fmt::Debug
+    /// A view of the whole list: a plain slice for lists stored contiguously,
+    /// or a view type such as [`BitSlice`](crate::BitSlice) for packed ones.
+    type Slice<'a>: PartialEq<&'a [T]> + core::fmt::Debug
     where
         T: 'a,
         Self: 'a;
@@ -0,0 +1,199 @@
+use core::ops::{Bound, Range, RangeBounds};
+use core::ptr;
+
+use crate::{Collection, Error, ListMut};
+
+pub trait ListDrain<T>: ListMut<T> {
+    /// Iterator over the elements removed by [`ListDrain::drain`].
+    type Drain<'a>: Iterator<Item = T>
+    where
+        T: 'a,
+        Self: 'a;
+
+    /// Iterator over the elements replaced by [`ListDrain::splice`].
+    type Splice<'a>: Iterator<Item = T>
+    where
+        T: 'a,
+        Self: 'a;
+
+    /// Iterator returned by [`ListDrain::extract_if`].
+    type ExtractIf<'a, F>: Iterator<Item = T>
+    where
+        T: 'a,
+        Self: 'a,
+        F: FnMut(&mut T) -> bool;
+
+    /// Removes the elements in `range`, yielding them in order. Elements
+    /// the iterator is dropped before reaching are removed as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or decreasing.
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where
+        R: RangeBounds<usize>;
+
+    /// Replaces the elements in `range` with `replace_with`, returning the
+    /// removed ones.
+    ///
+    /// Unlike `Vec::splice`, the replacement is inserted before this
+    /// returns, so a list that cannot hold the result fails with
+    /// [`Error::CapacityExceeded`] and is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or decreasing.
+    fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<Self::Splice<'_>, Error<T>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator;
+
+    /// Removes and yields the elements in `range` for which `filter`
+    /// returns `true`. Elements the iterator is dropped before reaching are
+    /// kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or decreasing.
+    fn extract_if<R, F>(&mut self, range: R, filter: F) -> Self::ExtractIf<'_, F>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool;
+
+    /// Appends clones of `other`, or fails with [`Error::CapacityExceeded`]
+    /// without appending any if they do not all fit.
+    fn extend_from_slice(&mut self, other: &[T]) -> Result<(), Error<T>>
+    where
+        T: Clone;
+
+    /// Appends clones of the elements in `src`, or fails with
+    /// [`Error::CapacityExceeded`] without appending any if they do not all
+    /// fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` is out of bounds or decreasing.
+    fn extend_from_within<R>(&mut self, src: R) -> Result<(), Error<T>>
+    where
+        R: RangeBounds<usize>,
+        T: Clone;
+}
+
+/// Iterator returned by [`ListDrain::extract_if`] for contiguous lists.
+///
+/// Like `Vec::extract_if`, kept elements are shifted back over the
+/// extracted ones as the iterator goes, so a full pass is linear. The list
+/// reports a length of zero until the iterator is dropped, so leaking it
+/// leaks the elements instead of exposing moved-out ones.
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExtractIf<'a, T, L, F> {
+    list: &'a mut L,
+    as_mut_ptr: fn(&mut L) -> *mut T,
+    set_len: unsafe fn(&mut L, usize),
+    index: usize,
+    end: usize,
+    deleted: usize,
+    old_len: usize,
+    filter: F,
+}
+
+impl<'a, T, L: Collection<T>, F> ExtractIf<'a, T, L, F> {
+    /// # Safety
+    ///
+    /// `as_mut_ptr` must return the start of the list's elements and
+    /// `set_len` must only change the length, as `Vec`'s methods do.
+    #[cfg_attr(not(any(feature = "alloc", feature = "heapless")), allow(dead_code))]
+    pub(crate) unsafe fn new<R>(
+        list: &'a mut L,
+        range: R,
+        filter: F,
+        as_mut_ptr: fn(&mut L) -> *mut T,
+        set_len: unsafe fn(&mut L, usize),
+    ) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let old_len = list.len();
+        let Range { start, end } = to_range(range, old_len);
+        set_len(list, 0);
+        ExtractIf {
+            list,
+            as_mut_ptr,
+            set_len,
+            index: start,
+            end,
+            deleted: 0,
+            old_len,
+            filter,
+        }
+    }
+}
+
+impl<T, L, F> Iterator for ExtractIf<'_, T, L, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let base = (self.as_mut_ptr)(self.list);
+        while self.index < self.end {
+            // SAFETY: `index < end <= old_len`, and every element from
+            // `index` on is still in place; the `deleted` slots before it
+            // have been moved out or over.
+            unsafe {
+                let current = base.add(self.index);
+                let extract = (self.filter)(&mut *current);
+                self.index += 1;
+                if extract {
+                    self.deleted += 1;
+                    return Some(ptr::read(current));
+                }
+                if self.deleted > 0 {
+                    ptr::copy_nonoverlapping(current, current.sub(self.deleted), 1);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.index))
+    }
+}
+
+impl<T, L, F> Drop for ExtractIf<'_, T, L, F> {
+    fn drop(&mut self) {
+        let base = (self.as_mut_ptr)(self.list);
+        // SAFETY: the elements from `index` on were never visited, so
+        // shifting them back over the gap leaves `old_len - deleted`
+        // initialized elements in order.
+        unsafe {
+            if self.index < self.old_len && self.deleted > 0 {
+                let tail = base.add(self.index);
+                ptr::copy(tail, tail.sub(self.deleted), self.old_len - self.index);
+            }
+            (self.set_len)(self.list, self.old_len - self.deleted);
+        }
+    }
+}
+
+/// Resolves `range` against a list of length `len`, panicking like slice
+/// indexing does if it is out of bounds or decreasing.
+pub(crate) fn to_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("range start overflows"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end overflows"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range starts at {start} but ends at {end}");
+    assert!(end <= len, "range end {end} out of bounds for length {len}");
+    start..end
+}
@@ -1,17 +1,33 @@
+use core::ops::DerefMut;
+
 use crate::{CollectionMut, Error, List};
 
 pub trait ListMut<T>: List<T> + CollectionMut<T> {
-    fn new() -> Self
+    /// Mutable access to one element: `&mut T` for lists that store their
+    /// elements directly, or a proxy that writes back on drop for packed
+    /// lists such as [`BitList`](crate::BitList).
+    type ElementMut<'a>: DerefMut<Target = T>
     where
-        Self: Sized;
+        T: 'a,
+        Self: 'a;
 
     fn push(&mut self, item: T) -> Result<(), Error<T>>;
     fn pop(&mut self) -> Option<T>;
     fn capacity(&self) -> usize;
 
-    fn first_mut(&mut self) -> Option<&mut T>;
-    fn last_mut(&mut self) -> Option<&mut T>;
-    fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+    fn first_mut(&mut self) -> Option<Self::ElementMut<'_>>;
+    fn last_mut(&mut self) -> Option<Self::ElementMut<'_>>;
+    fn get_mut(&mut self, index: usize) -> Option<Self::ElementMut<'_>>;
+
+    /// Replaces the element at `index`, returning the old one, or returning
+    /// `value` back inside [`Error::InsertFailed`] if `index` is out of
+    /// bounds.
+    fn set(&mut self, index: usize, value: T) -> Result<T, Error<T>> {
+        match self.get_mut(index) {
+            Some(mut slot) => Ok(core::mem::replace(&mut *slot, value)),
+            None => Err(Error::InsertFailed(value)),
+        }
+    }
 
     fn insert(&mut self, index: usize, element: T) -> Result<(), Error<T>>;
     fn remove(&mut self, index: usize) -> T;
@@ -0,0 +1,12 @@
+use crate::ListMut;
+
+/// Lists that can be created empty out of nothing.
+///
+/// Kept apart from [`ListMut`] so that a list that has to be handed
+/// something to be built from, such as an `AllocVec` whose allocator is a
+/// borrowed arena, can still be modified through the generic traits.
+pub trait ListNew<T>: ListMut<T> {
+    fn new() -> Self
+    where
+        Self: Sized;
+}
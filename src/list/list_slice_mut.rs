@@ -0,0 +1,59 @@
+use core::ops::RangeBounds;
+
+/// Lists stored as one contiguous slice, exposing the slice operations that
+/// need mutable access without changing the length.
+///
+/// Only [`ListSliceMut::as_mut_slice`] is required; everything else works on
+/// the slice it returns.
+pub trait ListSliceMut<T> {
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    #[inline(always)]
+    fn rotate_left(&mut self, mid: usize) {
+        self.as_mut_slice().rotate_left(mid)
+    }
+
+    #[inline(always)]
+    fn rotate_right(&mut self, k: usize) {
+        self.as_mut_slice().rotate_right(k)
+    }
+
+    #[inline(always)]
+    fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        self.as_mut_slice().split_at_mut(mid)
+    }
+
+    #[inline(always)]
+    fn chunks_mut(&mut self, chunk_size: usize) -> core::slice::ChunksMut<'_, T> {
+        self.as_mut_slice().chunks_mut(chunk_size)
+    }
+
+    #[inline(always)]
+    fn copy_within<R>(&mut self, src: R, dest: usize)
+    where
+        R: RangeBounds<usize>,
+        T: Copy,
+    {
+        self.as_mut_slice().copy_within(src, dest)
+    }
+
+    #[inline(always)]
+    fn swap_with_slice(&mut self, other: &mut [T]) {
+        self.as_mut_slice().swap_with_slice(other)
+    }
+
+    #[inline(always)]
+    fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().select_nth_unstable(index)
+    }
+}
+
+impl<T, const N: usize> ListSliceMut<T> for [T; N] {
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
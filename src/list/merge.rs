@@ -0,0 +1,296 @@
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::Iterable;
+use crate::{Error, List, ListMut, ListSortable};
+
+/// Merges the sorted lists `a` and `b` onto the end of `out`.
+///
+/// The merge is stable: equal elements keep their order, and those of `a`
+/// come before those of `b`. If `out` fills up, the element that did not
+/// fit is returned in [`Error::InsertFailed`] and `out` keeps everything
+/// merged before it.
+pub fn merge_sorted_into<T, A, B, O>(a: &A, b: &B, out: &mut O) -> Result<(), Error<T>>
+where
+    T: Ord + Clone,
+    A: List<T>,
+    B: List<T>,
+    O: ListMut<T>,
+{
+    merge_sorted_into_by(a, b, out, T::cmp)
+}
+
+/// Like [`merge_sorted_into`], with lists sorted by `compare`.
+pub fn merge_sorted_into_by<T, A, B, O, F>(
+    a: &A,
+    b: &B,
+    out: &mut O,
+    mut compare: F,
+) -> Result<(), Error<T>>
+where
+    T: Clone,
+    A: List<T>,
+    B: List<T>,
+    O: ListMut<T>,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (mut i, mut j) = (0, 0);
+    loop {
+        let next = match (a.get(i), b.get(j)) {
+            (Some(x), Some(y)) if compare(y, x) == Ordering::Less => {
+                j += 1;
+                y
+            }
+            (Some(x), _) => {
+                i += 1;
+                x
+            }
+            (None, Some(y)) => {
+                j += 1;
+                y
+            }
+            (None, None) => return Ok(()),
+        };
+        out.push(next.clone())?;
+    }
+}
+
+/// Merges the sorted runs `list[..mid]` and `list[mid..]` in place.
+///
+/// The merge is stable and needs no extra memory, at the cost of
+/// O(n log n) swaps for runs of similar length.
+///
+/// # Panics
+///
+/// Panics if `mid` is greater than the length of the list.
+pub fn merge_inplace<T, L>(list: &mut L, mid: usize)
+where
+    T: Ord,
+    L: ListSortable<T>,
+{
+    merge_inplace_by(list, mid, T::cmp)
+}
+
+/// Like [`merge_inplace`], with runs sorted by `compare`.
+pub fn merge_inplace_by<T, L, F>(list: &mut L, mid: usize, mut compare: F)
+where
+    L: ListSortable<T>,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = list.len();
+    assert!(mid <= len, "mid {mid} out of bounds for length {len}");
+    merge_runs(list, 0, mid, len, &mut compare);
+}
+
+/// Rotation-based merge: split the longer run in half, find where its
+/// middle element lands in the other run, rotate the two pieces between
+/// into place and merge either side.
+fn merge_runs<T, L, F>(list: &mut L, lo: usize, mid: usize, hi: usize, compare: &mut F)
+where
+    L: ListMut<T>,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if lo == mid || mid == hi {
+        return;
+    }
+    if mid - lo == 1 && hi - mid == 1 {
+        if less(list, mid, lo, compare) {
+            list.swap(lo, mid);
+        }
+        return;
+    }
+
+    let (left_cut, right_cut) = if mid - lo > hi - mid {
+        let left_cut = lo + (mid - lo) / 2;
+        // Elements of the right run equal to the pivot stay behind it.
+        let right_cut = partition_point(mid, hi, |i| less(list, i, left_cut, compare));
+        (left_cut, right_cut)
+    } else {
+        let right_cut = mid + (hi - mid) / 2;
+        // Elements of the left run equal to the pivot stay ahead of it.
+        let left_cut = partition_point(lo, mid, |i| !less(list, right_cut, i, compare));
+        (left_cut, right_cut)
+    };
+
+    rotate(list, left_cut, mid, right_cut);
+    let new_mid = left_cut + (right_cut - mid);
+    merge_runs(list, lo, left_cut, new_mid, compare);
+    merge_runs(list, new_mid, right_cut, hi, compare);
+}
+
+fn less<T, L, F>(list: &L, a: usize, b: usize, compare: &mut F) -> bool
+where
+    L: List<T>,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    compare(list.get(a).unwrap(), list.get(b).unwrap()) == Ordering::Less
+}
+
+/// First index in `lo..hi` for which `pred` is false, assuming it holds
+/// for a prefix of the range.
+fn partition_point(mut lo: usize, mut hi: usize, mut pred: impl FnMut(usize) -> bool) -> usize {
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Turns `list[lo..mid] ++ list[mid..hi]` into `list[mid..hi] ++
+/// list[lo..mid]` with three reversals.
+fn rotate<T, L: ListMut<T>>(list: &mut L, lo: usize, mid: usize, hi: usize) {
+    reverse(list, lo, mid);
+    reverse(list, mid, hi);
+    reverse(list, lo, hi);
+}
+
+fn reverse<T, L: ListMut<T>>(list: &mut L, mut lo: usize, mut hi: usize) {
+    while lo + 1 < hi {
+        hi -= 1;
+        list.swap(lo, hi);
+        lo += 1;
+    }
+}
+
+/// Iterator merging any number of sorted iterators, returned by [`kmerge`]
+/// and [`kmerge_by`].
+///
+/// The heads of the inputs are kept in a binary heap, so each item costs
+/// O(log k) comparisons for k inputs.
+#[cfg(feature = "alloc")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct KMerge<I: Iterator, F> {
+    // Min-heap of each non-empty input's next item, tagged with the input's
+    // position so ties go to the earlier input.
+    heap: Vec<(I::Item, usize, I)>,
+    compare: F,
+}
+
+#[cfg(feature = "alloc")]
+impl<I, F> KMerge<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    fn new(iters: impl IntoIterator<Item = I>, compare: F) -> Self {
+        let mut merge = KMerge {
+            heap: Vec::new(),
+            compare,
+        };
+        for (source, mut iter) in iters.into_iter().enumerate() {
+            if let Some(head) = iter.next() {
+                merge.heap.push((head, source, iter));
+            }
+        }
+        for i in (0..merge.heap.len() / 2).rev() {
+            merge.sift_down(i);
+        }
+        merge
+    }
+
+    fn before(&mut self, a: usize, b: usize) -> bool {
+        let (x, y) = (&self.heap[a], &self.heap[b]);
+        match (self.compare)(&x.0, &y.0) {
+            Ordering::Equal => x.1 < y.1,
+            ordering => ordering == Ordering::Less,
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let (left, right) = (2 * i + 1, 2 * i + 2);
+            let mut first = i;
+            if left < self.heap.len() && self.before(left, first) {
+                first = left;
+            }
+            if right < self.heap.len() && self.before(right, first) {
+                first = right;
+            }
+            if first == i {
+                return;
+            }
+            self.heap.swap(i, first);
+            i = first;
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, F> Iterator for KMerge<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let top = self.heap.first_mut()?;
+        let item = match top.2.next() {
+            Some(head) => core::mem::replace(&mut top.0, head),
+            None => self.heap.swap_remove(0).0,
+        };
+        self.sift_down(0);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.heap
+            .iter()
+            .fold((0, Some(0)), |(lo, hi), (_, _, iter)| {
+                let (iter_lo, iter_hi) = iter.size_hint();
+                let hi = match (hi, iter_hi) {
+                    (Some(hi), Some(iter_hi)) => hi.checked_add(iter_hi + 1),
+                    _ => None,
+                };
+                (lo.saturating_add(iter_lo + 1), hi)
+            })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, F> core::fmt::Debug for KMerge<I, F>
+where
+    I: Iterator + core::fmt::Debug,
+    I::Item: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KMerge").field("heap", &self.heap).finish()
+    }
+}
+
+/// Merges the items of any number of sorted collections into one sorted
+/// stream.
+///
+/// The merge is stable: equal items come out in the order of the
+/// collections they belong to, and in their original order within each.
+#[cfg(feature = "alloc")]
+#[allow(clippy::type_complexity)]
+pub fn kmerge<'c, C>(
+    collections: impl IntoIterator<Item = &'c C>,
+) -> KMerge<C::Iterator<'c>, fn(&C::Item<'c>, &C::Item<'c>) -> Ordering>
+where
+    C: Iterable + ?Sized + 'c,
+    C::Item<'c>: Ord,
+{
+    kmerge_by(collections, Ord::cmp)
+}
+
+/// Like [`kmerge`], with collections sorted by `compare`.
+#[cfg(feature = "alloc")]
+pub fn kmerge_by<'c, C, F>(
+    collections: impl IntoIterator<Item = &'c C>,
+    compare: F,
+) -> KMerge<C::Iterator<'c>, F>
+where
+    C: Iterable + ?Sized + 'c,
+    F: FnMut(&C::Item<'c>, &C::Item<'c>) -> Ordering,
+{
+    KMerge::new(collections.into_iter().map(C::iter), compare)
+}
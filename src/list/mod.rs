@@ -1,7 +1,20 @@
+#[cfg(feature = "allocator-api")]
+mod alloc_vec;
+mod bits;
+#[cfg(feature = "alloc")]
+mod diff;
 mod list;
+mod list_drain;
 mod list_mut;
+mod list_new;
 mod list_resizable;
+mod list_slice_mut;
 mod list_sortable;
+mod merge;
+#[cfg(feature = "alloc")]
+mod persistent;
+mod ring;
+mod sorted;
 
 #[cfg(feature = "heapless")]
 mod heapless;
@@ -9,7 +22,25 @@ mod heapless;
 #[cfg(feature = "alloc")]
 mod vec;
 
+#[cfg(feature = "alloc")]
+pub use diff::{diff, lcs, Edit, Patch};
 pub use list::List;
+pub(crate) use list_drain::to_range;
+pub use list_drain::{ExtractIf, ListDrain};
 pub use list_mut::ListMut;
+pub use list_new::ListNew;
 pub use list_resizable::ListResizable;
+pub use list_slice_mut::ListSliceMut;
 pub use list_sortable::ListSortable;
+#[cfg(feature = "alloc")]
+pub use merge::{kmerge, kmerge_by, KMerge};
+pub use merge::{merge_inplace, merge_inplace_by, merge_sorted_into, merge_sorted_into_by};
+#[cfg(feature = "alloc")]
+pub use persistent::PList;
+#[cfg(feature = "alloc")]
+pub use bits::BitList;
+pub use bits::{BitArray, BitIter, BitMut, BitSlice, WordList};
+#[cfg(feature = "alloc")]
+pub use ring::HeapRingBuffer;
+pub use ring::{Ring, RingBuffer, RingSlice, RingStorage};
+pub use sorted::{SortedList, SortedSet};
@@ -0,0 +1,669 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use super::{List, ListMut, ListNew};
+use crate::{Collection, CollectionMut, Error, Iterable};
+
+/// Most elements in a leaf, or children in a branch.
+const BRANCH: usize = 32;
+
+#[derive(Clone)]
+enum Node<T> {
+    Leaf(Vec<T>),
+    Branch {
+        len: usize,
+        children: Vec<Arc<Node<T>>>,
+    },
+}
+
+impl<T> Node<T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        match self {
+            Node::Leaf(items) => items.len(),
+            Node::Branch { len, .. } => *len,
+        }
+    }
+
+    /// Number of elements or children held directly.
+    #[inline(always)]
+    fn width(&self) -> usize {
+        match self {
+            Node::Leaf(items) => items.len(),
+            Node::Branch { children, .. } => children.len(),
+        }
+    }
+
+    fn get(&self, mut index: usize) -> Option<&T> {
+        let mut node = self;
+        loop {
+            match node {
+                Node::Leaf(items) => return items.get(index),
+                Node::Branch { children, .. } => {
+                    let (child, offset) = locate(children, index)?;
+                    node = &children[child];
+                    index = offset;
+                }
+            }
+        }
+    }
+
+    /// Builds a balanced tree over `items`.
+    fn build(items: Vec<T>) -> Self {
+        if items.len() <= BRANCH {
+            return Node::Leaf(items);
+        }
+        let mut level = Vec::new();
+        let mut items = items.into_iter().peekable();
+        while items.peek().is_some() {
+            level.push(Arc::new(Node::Leaf(items.by_ref().take(BRANCH).collect())));
+        }
+        while level.len() > BRANCH {
+            let mut parents = Vec::new();
+            let mut nodes = level.into_iter().peekable();
+            while nodes.peek().is_some() {
+                let children: Vec<_> = nodes.by_ref().take(BRANCH).collect();
+                let len = children.iter().map(|child| child.len()).sum();
+                parents.push(Arc::new(Node::Branch { len, children }));
+            }
+            level = parents;
+        }
+        let len = level.iter().map(|child| child.len()).sum();
+        Node::Branch {
+            len,
+            children: level,
+        }
+    }
+}
+
+impl<T: Clone> Node<T> {
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match self {
+            Node::Leaf(items) => items.get_mut(index),
+            Node::Branch { children, .. } => {
+                let (child, offset) = locate(children, index)?;
+                Arc::make_mut(&mut children[child]).get_mut(offset)
+            }
+        }
+    }
+
+    /// Inserts `value` at `index`, returning the right half of this node if
+    /// it had to split.
+    fn insert(&mut self, index: usize, value: T) -> Option<Self> {
+        match self {
+            Node::Leaf(items) => {
+                items.insert(index, value);
+                (items.len() > BRANCH).then(|| Node::Leaf(items.split_off(BRANCH / 2)))
+            }
+            Node::Branch { len, children } => {
+                *len += 1;
+                let (child, offset) = locate_end(children, index);
+                let split = Arc::make_mut(&mut children[child]).insert(offset, value)?;
+                children.insert(child + 1, Arc::new(split));
+                if children.len() <= BRANCH {
+                    return None;
+                }
+                let right = children.split_off(BRANCH / 2);
+                let right_len = right.iter().map(|child| child.len()).sum::<usize>();
+                *len -= right_len;
+                Some(Node::Branch {
+                    len: right_len,
+                    children: right,
+                })
+            }
+        }
+    }
+
+    /// Removes the element at `index`, which must be in bounds, merging
+    /// children that fall below half full with a neighbour.
+    fn remove(&mut self, index: usize) -> T {
+        match self {
+            Node::Leaf(items) => items.remove(index),
+            Node::Branch { len, children } => {
+                *len -= 1;
+                let (child, offset) = locate(children, index).expect("index in bounds");
+                let value = Arc::make_mut(&mut children[child]).remove(offset);
+                if children[child].width() < BRANCH / 2 && children.len() > 1 {
+                    let left = if child + 1 < children.len() {
+                        child
+                    } else {
+                        child - 1
+                    };
+                    merge(children, left);
+                }
+                value
+            }
+        }
+    }
+
+    /// Moves the contents of `other`, a node at the same depth, onto the end
+    /// of this one.
+    fn absorb(&mut self, other: Self) {
+        match (self, other) {
+            (Node::Leaf(items), Node::Leaf(mut more)) => items.append(&mut more),
+            (
+                Node::Branch { len, children },
+                Node::Branch {
+                    len: more_len,
+                    children: mut more,
+                },
+            ) => {
+                *len += more_len;
+                children.append(&mut more);
+            }
+            _ => unreachable!("leaves are all at the same depth"),
+        }
+    }
+
+    /// Splits off the back half of an overfull node.
+    fn halve(&mut self) -> Self {
+        match self {
+            Node::Leaf(items) => Node::Leaf(items.split_off(items.len() / 2)),
+            Node::Branch { len, children } => {
+                let right = children.split_off(children.len() / 2);
+                let right_len = right.iter().map(|child| child.len()).sum::<usize>();
+                *len -= right_len;
+                Node::Branch {
+                    len: right_len,
+                    children: right,
+                }
+            }
+        }
+    }
+}
+
+/// Child holding `index` and the index within it.
+fn locate<T>(children: &[Arc<Node<T>>], mut index: usize) -> Option<(usize, usize)> {
+    for (i, child) in children.iter().enumerate() {
+        if index < child.len() {
+            return Some((i, index));
+        }
+        index -= child.len();
+    }
+    None
+}
+
+/// Like [`locate`], but an index one past the end lands in the last child.
+fn locate_end<T>(children: &[Arc<Node<T>>], index: usize) -> (usize, usize) {
+    locate(children, index).unwrap_or_else(|| {
+        let last = children.len() - 1;
+        (last, children[last].len())
+    })
+}
+
+/// Joins `children[left + 1]` into `children[left]`, splitting the result
+/// again if it is overfull.
+fn merge<T: Clone>(children: &mut Vec<Arc<Node<T>>>, left: usize) {
+    let right = children.remove(left + 1);
+    let right = Arc::try_unwrap(right).unwrap_or_else(|shared| (*shared).clone());
+    let node = Arc::make_mut(&mut children[left]);
+    node.absorb(right);
+    if node.width() > BRANCH {
+        let right = node.halve();
+        children.insert(left + 1, Arc::new(right));
+    }
+}
+
+/// Persistent list sharing structure between copies.
+///
+/// Elements live in a balanced tree of chunks behind [`Arc`]s, so
+/// [`Clone::clone`] is O(1) and a clone is an independent snapshot: changing
+/// one copy duplicates only the chunks on the path to the changed element,
+/// leaving every other copy as it was. Indexing, [`ListMut::push`],
+/// [`ListMut::insert`] and [`ListMut::remove`] are O(log n).
+///
+/// Operations that touch every element, such as [`ListMut::retain`] or
+/// [`ListMut::reverse`], rebuild the tree in O(n).
+pub struct PList<T> {
+    root: Arc<Node<T>>,
+}
+
+impl<T> PList<T> {
+    pub fn new() -> Self {
+        Self {
+            root: Arc::new(Node::Leaf(Vec::new())),
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.root.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.root.get(index)
+    }
+
+    /// Whether `self` and `other` are copies of the same snapshot, sharing
+    /// all of their structure.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.root, &other.root)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            stack: Vec::from([core::slice::from_ref(&self.root).iter()]),
+            leaf: [].iter(),
+            remaining: self.len(),
+        }
+    }
+}
+
+impl<T: Clone> PList<T> {
+    /// Replaces the contents with a tree built from `items`.
+    fn rebuild(&mut self, items: Vec<T>) {
+        self.root = Arc::new(Node::build(items));
+    }
+
+    fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    /// Drops tree levels left with a single child.
+    fn shrink(&mut self) {
+        while let Node::Branch { children, .. } = &*self.root {
+            if children.len() != 1 {
+                break;
+            }
+            self.root = children[0].clone();
+        }
+    }
+}
+
+impl<T> Clone for PList<T> {
+    /// Makes a snapshot in O(1).
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+        }
+    }
+}
+
+impl<T> Default for PList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for PList<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || (self.len() == other.len() && self.iter().eq(other.iter()))
+    }
+}
+
+impl<T: Eq> Eq for PList<T> {}
+
+impl<'a, T: PartialEq> PartialEq<&'a [T]> for &PList<T> {
+    fn eq(&self, other: &&'a [T]) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> FromIterator<T> for PList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            root: Arc::new(Node::build(iter.into_iter().collect())),
+        }
+    }
+}
+
+/// Iterator over the elements of a [`PList`].
+pub struct Iter<'a, T> {
+    stack: Vec<core::slice::Iter<'a, Arc<Node<T>>>>,
+    leaf: core::slice::Iter<'a, T>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(item) = self.leaf.next() {
+                self.remaining -= 1;
+                return Some(item);
+            }
+            let level = self.stack.last_mut()?;
+            match level.next().map(|node| &**node) {
+                Some(Node::Leaf(items)) => self.leaf = items.iter(),
+                Some(Node::Branch { children, .. }) => self.stack.push(children.iter()),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Iterable for PList<T> {
+    type Item<'collection>
+        = &'collection T
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection, T>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        PList::iter(self)
+    }
+}
+
+impl<T> Collection<T> for PList<T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        PList::len(self)
+    }
+
+    fn contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|x| x == other)
+    }
+}
+
+impl<T> CollectionMut<T> for PList<T> {
+    /// Empties this copy, leaving other snapshots untouched.
+    fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<T: PartialEq + Debug> List<T> for PList<T> {
+    type Slice<'a>
+        = &'a PList<T>
+    where
+        T: 'a;
+
+    /// Returns the list itself, which compares equal to slices with the same
+    /// elements.
+    #[inline(always)]
+    fn as_slice(&self) -> Self::Slice<'_> {
+        self
+    }
+
+    fn find_index(&self, other: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|x| x == other)
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Option<&T> {
+        self.root.get(0)
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Option<&T> {
+        self.root.get(self.len().checked_sub(1)?)
+    }
+
+    #[inline(always)]
+    fn get(&self, index: usize) -> Option<&T> {
+        self.root.get(index)
+    }
+
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|item| item.cmp(x))
+    }
+
+    fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match f(&self[mid]) {
+                core::cmp::Ordering::Less => low = mid + 1,
+                core::cmp::Ordering::Greater => high = mid,
+                core::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|item| f(item).cmp(b))
+    }
+
+    fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        needle.len() <= self.len() && self.iter().zip(needle).all(|(a, b)| a == b)
+    }
+
+    fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        needle.len() <= self.len()
+            && self
+                .iter()
+                .skip(self.len() - needle.len())
+                .zip(needle)
+                .all(|(a, b)| a == b)
+    }
+}
+
+impl<T> core::ops::Index<usize> for PList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.root.get(index) {
+            Some(item) => item,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len()
+            ),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + Debug> ListNew<T> for PList<T> {
+    #[inline(always)]
+    fn new() -> Self {
+        PList::new()
+    }
+}
+
+impl<T: Clone + PartialEq + Debug> ListMut<T> for PList<T> {
+    type ElementMut<'a>
+        = &'a mut T
+    where
+        T: 'a;
+
+    fn push(&mut self, item: T) -> Result<(), Error<T>> {
+        let len = self.len();
+        ListMut::insert(self, len, item)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+        Some(ListMut::remove(self, last))
+    }
+
+    /// Nothing is allocated ahead of time, so this is the length.
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn first_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.get_mut(0)
+    }
+
+    fn last_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.get_mut(self.len().checked_sub(1)?)
+    }
+
+    /// Copies the path to the element first if it is shared with another
+    /// snapshot.
+    fn get_mut(&mut self, index: usize) -> Option<Self::ElementMut<'_>> {
+        if index >= self.len() {
+            return None;
+        }
+        Arc::make_mut(&mut self.root).get_mut(index)
+    }
+
+    fn insert(&mut self, index: usize, element: T) -> Result<(), Error<T>> {
+        if index > self.len() {
+            return Err(Error::InsertFailed(element));
+        }
+        if let Some(right) = Arc::make_mut(&mut self.root).insert(index, element) {
+            let left = core::mem::replace(&mut self.root, Arc::new(Node::Leaf(Vec::new())));
+            self.root = Arc::new(Node::Branch {
+                len: left.len() + right.len(),
+                children: Vec::from([left, Arc::new(right)]),
+            });
+        }
+        Ok(())
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+        let value = Arc::make_mut(&mut self.root).remove(index);
+        self.shrink();
+        value
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        let last = self.len().wrapping_sub(1);
+        ListMut::swap(self, index, last);
+        ListMut::remove(self, last)
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        let len = self.len();
+        if a >= len || b >= len {
+            panic!("swap index out of bounds: the len is {len} but the indices are {a} and {b}");
+        }
+        if a == b {
+            return;
+        }
+        let value = self[a].clone();
+        let value = core::mem::replace(&mut *self.get_mut(b).expect("index in bounds"), value);
+        *self.get_mut(a).expect("index in bounds") = value;
+    }
+
+    fn reverse(&mut self) {
+        let mut items = self.to_vec();
+        items.reverse();
+        self.rebuild(items);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            let items = self.iter().take(len).cloned().collect();
+            self.rebuild(items);
+        }
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let items = self.iter().filter(|x| f(x)).cloned().collect();
+        self.rebuild(items);
+    }
+
+    fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut items = self.to_vec();
+        items.retain_mut(f);
+        self.rebuild(items);
+    }
+
+    fn dedup(&mut self) {
+        let mut items = self.to_vec();
+        items.dedup();
+        self.rebuild(items);
+    }
+
+    fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mut items = self.to_vec();
+        items.dedup_by(same_bucket);
+        self.rebuild(items);
+    }
+
+    fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        let mut items = self.to_vec();
+        items.dedup_by_key(key);
+        self.rebuild(items);
+    }
+
+    fn fill(&mut self, value: T) {
+        let items = alloc::vec![value; self.len()];
+        self.rebuild(items);
+    }
+
+    fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        let items = (0..self.len()).map(|_| f()).collect();
+        self.rebuild(items);
+    }
+
+    fn append(&mut self, other: &mut Self) -> Result<(), Error<T>> {
+        let items = self.iter().chain(other.iter()).cloned().collect();
+        self.rebuild(items);
+        other.clear();
+        Ok(())
+    }
+
+    fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        let tail = self.iter().skip(at).cloned().collect();
+        self.truncate(at);
+        tail
+    }
+}
@@ -0,0 +1,616 @@
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::iter::Chain;
+use core::mem::MaybeUninit;
+use core::slice;
+
+use super::List;
+use crate::{Collection, CollectionMut, Error, Iterable};
+
+/// Backing memory for a [`Ring`]: one slot per element of capacity.
+///
+/// # Safety
+///
+/// Both methods must always return the same memory with the same length.
+pub unsafe trait RingStorage<T> {
+    fn slots(&self) -> &[MaybeUninit<T>];
+    fn slots_mut(&mut self) -> &mut [MaybeUninit<T>];
+}
+
+unsafe impl<T, const N: usize> RingStorage<T> for [MaybeUninit<T>; N] {
+    #[inline(always)]
+    fn slots(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+
+    #[inline(always)]
+    fn slots_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T> RingStorage<T> for alloc::boxed::Box<[MaybeUninit<T>]> {
+    #[inline(always)]
+    fn slots(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+
+    #[inline(always)]
+    fn slots_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+
+/// Fixed-capacity circular buffer, indexed from the oldest element.
+///
+/// The live elements run from the oldest one and wrap around the end of the
+/// storage, so once the buffer has wrapped they occupy two runs of slots;
+/// see [`Ring::as_slices`]. [`Ring::make_contiguous`] rotates them back into
+/// one.
+///
+/// Use [`RingBuffer`] for an array-backed buffer that needs no allocator, or
+/// [`HeapRingBuffer`] for one sized at runtime.
+pub struct Ring<T, S: RingStorage<T>> {
+    buf: S,
+    head: usize,
+    len: usize,
+    _marker: core::marker::PhantomData<T>,
+}
+
+/// Array-backed [`Ring`] holding at most `N` elements.
+pub type RingBuffer<T, const N: usize> = Ring<T, [MaybeUninit<T>; N]>;
+
+/// Heap-allocated [`Ring`] with a capacity chosen at runtime.
+#[cfg(feature = "alloc")]
+pub type HeapRingBuffer<T> = Ring<T, alloc::boxed::Box<[MaybeUninit<T>]>>;
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    pub fn new() -> Self {
+        Self::from_storage(
+            // SAFETY: an array of `MaybeUninit` needs no initialisation.
+            unsafe { MaybeUninit::uninit().assume_init() },
+        )
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> HeapRingBuffer<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_storage((0..capacity).map(|_| MaybeUninit::uninit()).collect())
+    }
+}
+
+impl<T, S: RingStorage<T>> Ring<T, S> {
+    fn from_storage(buf: S) -> Self {
+        Self {
+            buf,
+            head: 0,
+            len: 0,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.buf.slots().len()
+    }
+
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Appends `value` as the newest element, failing if the buffer is full.
+    pub fn push(&mut self, value: T) -> Result<(), Error<T>> {
+        if self.is_full() {
+            return Err(Error::InsertFailed(value));
+        }
+        self.write(value);
+        Ok(())
+    }
+
+    /// Appends `value` as the newest element, evicting and returning the
+    /// oldest one if the buffer is full.
+    ///
+    /// A zero-capacity buffer hands `value` straight back.
+    pub fn push_overwrite(&mut self, value: T) -> Option<T> {
+        if self.capacity() == 0 {
+            return Some(value);
+        }
+        let evicted = if self.is_full() {
+            self.pop_oldest()
+        } else {
+            None
+        };
+        self.write(value);
+        evicted
+    }
+
+    /// Removes and returns the oldest element.
+    pub fn pop_oldest(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: `head` holds the oldest live element, and the window is
+        // then moved past it so the value is never read again.
+        let value = unsafe { self.buf.slots()[self.head].assume_init_read() };
+        self.head = self.slot(1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Removes and returns the newest element.
+    pub fn pop_newest(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let slot = self.slot(self.len);
+        // SAFETY: the slot held the newest live element, and the window no
+        // longer covers it.
+        Some(unsafe { self.buf.slots()[slot].assume_init_read() })
+    }
+
+    pub fn oldest(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn newest(&self) -> Option<&T> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// Returns the element `index` places after the oldest.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: the slot lies within the live window.
+        Some(unsafe { self.buf.slots()[self.slot(index)].assume_init_ref() })
+    }
+
+    /// The contents as two slices, oldest first, in the manner of
+    /// `VecDeque::as_slices`. The second slice is empty unless the contents
+    /// wrap around the end of the storage.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.split();
+        let slots = self.buf.slots();
+        // SAFETY: both ranges lie within the live window, which is
+        // initialised.
+        unsafe {
+            (
+                &*(&slots[front] as *const [MaybeUninit<T>] as *const [T]),
+                &*(&slots[back] as *const [MaybeUninit<T>] as *const [T]),
+            )
+        }
+    }
+
+    /// The contents as two mutable slices, oldest first.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.split();
+        let (wrapped, rest) = self.buf.slots_mut().split_at_mut(front.start);
+        // SAFETY: both ranges lie within the live window, which is
+        // initialised, and they do not overlap since `back` ends before
+        // `front` starts.
+        unsafe {
+            (
+                &mut *(&mut rest[..front.len()] as *mut [MaybeUninit<T>] as *mut [T]),
+                &mut *(&mut wrapped[back] as *mut [MaybeUninit<T>] as *mut [T]),
+            )
+        }
+    }
+
+    /// Rotates the contents so they start at the front of the storage, and
+    /// returns them as one slice.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        let head = self.head;
+        self.buf.slots_mut().rotate_left(head);
+        self.head = 0;
+        self.as_mut_slices().0
+    }
+
+    /// The physical slot of the element `index` places after the oldest,
+    /// for any `index` up to the capacity.
+    #[inline(always)]
+    fn slot(&self, index: usize) -> usize {
+        let until_wrap = self.capacity() - self.head;
+        if index < until_wrap {
+            self.head + index
+        } else {
+            index - until_wrap
+        }
+    }
+
+    /// The slot ranges of the live elements before and after the wrap.
+    fn split(&self) -> (core::ops::Range<usize>, core::ops::Range<usize>) {
+        let until_wrap = self.capacity() - self.head;
+        if self.len <= until_wrap {
+            (self.head..self.head + self.len, 0..0)
+        } else {
+            (self.head..self.capacity(), 0..self.len - until_wrap)
+        }
+    }
+
+    /// Writes past the newest element; the caller ensures there is room.
+    fn write(&mut self, value: T) {
+        let slot = self.slot(self.len);
+        self.buf.slots_mut()[slot].write(value);
+        self.len += 1;
+    }
+}
+
+impl<T, S: RingStorage<T>> Drop for Ring<T, S> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Debug, S: RingStorage<T>> Debug for Ring<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Read-only view of the contents of a [`Ring`], the [`List::Slice`] of one.
+pub struct RingSlice<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+}
+
+impl<T> Clone for RingSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RingSlice<'_, T> {}
+
+impl<'a, T> RingSlice<'a, T> {
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        match index.checked_sub(self.front.len()) {
+            None => self.front.get(index),
+            Some(index) => self.back.get(index),
+        }
+    }
+
+    #[inline(always)]
+    pub fn first(&self) -> Option<&'a T> {
+        self.front.first().or_else(|| self.back.first())
+    }
+
+    #[inline(always)]
+    pub fn last(&self) -> Option<&'a T> {
+        self.back.last().or_else(|| self.front.last())
+    }
+
+    /// Searches the run after the wrap if the target sorts at or after its
+    /// first element, and the run before it otherwise.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (front, back) = (self.front, self.back);
+        match back.first().map(&mut f) {
+            Some(Ordering::Equal) => Ok(front.len()),
+            Some(Ordering::Less) => back
+                .binary_search_by(f)
+                .map(|i| i + front.len())
+                .map_err(|i| i + front.len()),
+            _ => front.binary_search_by(f),
+        }
+    }
+
+    pub fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        needle.len() <= self.len() && self.iter().zip(needle).all(|(a, b)| a == b)
+    }
+
+    pub fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        needle.len() <= self.len()
+            && self
+                .iter()
+                .skip(self.len() - needle.len())
+                .zip(needle)
+                .all(|(a, b)| a == b)
+    }
+
+    /// The two runs of elements, oldest first, as from [`Ring::as_slices`].
+    #[inline(always)]
+    pub fn as_slices(&self) -> (&'a [T], &'a [T]) {
+        (self.front, self.back)
+    }
+
+    pub fn iter(&self) -> Chain<slice::Iter<'a, T>, slice::Iter<'a, T>> {
+        self.front.iter().chain(self.back)
+    }
+}
+
+impl<T: Debug> Debug for RingSlice<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for RingSlice<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for RingSlice<'_, T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T, S: RingStorage<T>> Iterable for Ring<T, S> {
+    type Item<'collection>
+        = &'collection T
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Chain<slice::Iter<'collection, T>, slice::Iter<'collection, T>>
+    where
+        Self: 'collection;
+
+    /// Iterates from the oldest element to the newest.
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back)
+    }
+}
+
+impl<T, S: RingStorage<T>> Collection<T> for Ring<T, S> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (front, back) = self.as_slices();
+        front.contains(other) || back.contains(other)
+    }
+}
+
+impl<T, S: RingStorage<T>> CollectionMut<T> for Ring<T, S> {
+    fn clear(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        let (front, back): (*mut [T], *mut [T]) = (front, back);
+        self.head = 0;
+        self.len = 0;
+        // SAFETY: the window is emptied first, so a panicking destructor
+        // cannot cause a double drop.
+        unsafe {
+            core::ptr::drop_in_place(front);
+            core::ptr::drop_in_place(back);
+        }
+    }
+}
+
+impl<T: PartialEq + Debug, S: RingStorage<T>> List<T> for Ring<T, S> {
+    type Slice<'a>
+        = RingSlice<'a, T>
+    where
+        T: 'a,
+        Self: 'a;
+
+    #[inline(always)]
+    fn as_slice(&self) -> Self::Slice<'_> {
+        let (front, back) = self.as_slices();
+        RingSlice { front, back }
+    }
+
+    fn find_index(&self, other: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|x| x == other)
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Option<&T> {
+        self.oldest()
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Option<&T> {
+        self.newest()
+    }
+
+    #[inline(always)]
+    fn get(&self, index: usize) -> Option<&T> {
+        Ring::get(self, index)
+    }
+
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|e| e.cmp(x))
+    }
+
+    #[inline(always)]
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        List::as_slice(self).binary_search_by(f)
+    }
+
+    fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|e| f(e).cmp(b))
+    }
+
+    #[inline(always)]
+    fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        List::as_slice(self).starts_with(needle)
+    }
+
+    #[inline(always)]
+    fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        List::as_slice(self).ends_with(needle)
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod history {
+    use core::cmp::Ordering;
+    use core::fmt::Debug;
+
+    use heapless::history_buf::OldestOrdered;
+    use heapless::HistoryBuf;
+
+    use super::RingSlice;
+    use crate::{Collection, CollectionMut, Iterable, List};
+
+    impl<T, const N: usize> Iterable for HistoryBuf<T, N> {
+        type Item<'collection>
+            = &'collection T
+        where
+            Self: 'collection;
+
+        type Iterator<'collection>
+            = OldestOrdered<'collection, T>
+        where
+            Self: 'collection;
+
+        /// Iterates from the oldest element to the newest.
+        #[inline(always)]
+        fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+            self.oldest_ordered()
+        }
+    }
+
+    impl<T, const N: usize> Collection<T> for HistoryBuf<T, N> {
+        #[inline(always)]
+        fn len(&self) -> usize {
+            HistoryBuf::len(self)
+        }
+
+        fn contains(&self, other: &T) -> bool
+        where
+            T: PartialEq,
+        {
+            self.as_slice().contains(other)
+        }
+    }
+
+    impl<T, const N: usize> CollectionMut<T> for HistoryBuf<T, N> {
+        #[inline(always)]
+        fn clear(&mut self) {
+            HistoryBuf::clear(self)
+        }
+    }
+
+    /// Indexed from the oldest element, like [`Ring`](super::Ring).
+    impl<T: PartialEq + Debug, const N: usize> List<T> for HistoryBuf<T, N> {
+        type Slice<'a>
+            = RingSlice<'a, T>
+        where
+            T: 'a,
+            Self: 'a;
+
+        #[inline(always)]
+        fn as_slice(&self) -> Self::Slice<'_> {
+            let (front, back) = self.as_slices();
+            RingSlice { front, back }
+        }
+
+        fn find_index(&self, other: &T) -> Option<usize>
+        where
+            T: PartialEq,
+        {
+            self.iter().position(|x| x == other)
+        }
+
+        #[inline(always)]
+        fn first(&self) -> Option<&T> {
+            List::as_slice(self).first()
+        }
+
+        #[inline(always)]
+        fn last(&self) -> Option<&T> {
+            List::as_slice(self).last()
+        }
+
+        #[inline(always)]
+        fn get(&self, index: usize) -> Option<&T> {
+            List::as_slice(self).get(index)
+        }
+
+        fn binary_search(&self, x: &T) -> Result<usize, usize>
+        where
+            T: Ord,
+        {
+            self.binary_search_by(|e| e.cmp(x))
+        }
+
+        #[inline(always)]
+        fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+        where
+            F: FnMut(&T) -> Ordering,
+        {
+            List::as_slice(self).binary_search_by(f)
+        }
+
+        fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+        where
+            F: FnMut(&T) -> B,
+            B: Ord,
+        {
+            self.binary_search_by(|e| f(e).cmp(b))
+        }
+
+        #[inline(always)]
+        fn starts_with(&self, needle: &[T]) -> bool
+        where
+            T: PartialEq,
+        {
+            List::as_slice(self).starts_with(needle)
+        }
+
+        #[inline(always)]
+        fn ends_with(&self, needle: &[T]) -> bool
+        where
+            T: PartialEq,
+        {
+            List::as_slice(self).ends_with(needle)
+        }
+    }
+}
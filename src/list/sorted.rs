@@ -0,0 +1,318 @@
+use core::cmp::Ordering;
+use core::ops::{Bound, RangeBounds};
+
+use super::{List, ListMut, ListNew, ListSortable};
+use crate::{Collection, CollectionMut, Error, Iterable, Set, SetMut};
+
+/// List wrapper that keeps its elements in ascending order.
+///
+/// Lookups use binary search, so `contains` and `find_index` are O(log n).
+/// When `UNIQUE` is `true` (see [`SortedSet`]) equal elements are collapsed
+/// and the wrapper also implements [`Set`] and [`SetMut`].
+#[derive(Debug, Clone)]
+pub struct SortedList<L, const UNIQUE: bool = false> {
+    inner: L,
+}
+
+/// A [`SortedList`] that disallows duplicate elements.
+pub type SortedSet<L> = SortedList<L, true>;
+
+impl<L, const UNIQUE: bool> SortedList<L, UNIQUE> {
+    pub fn new<T>() -> Self
+    where
+        L: ListNew<T>,
+    {
+        Self { inner: L::new() }
+    }
+
+    /// Sorts `inner` (removing duplicates if `UNIQUE`) and wraps it.
+    pub fn from_unsorted<T: Ord>(mut inner: L) -> Self
+    where
+        L: ListSortable<T>,
+    {
+        inner.sort();
+        if UNIQUE {
+            inner.dedup();
+        }
+        Self { inner }
+    }
+
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> L {
+        self.inner
+    }
+
+    /// Index of the first element not less than `value`.
+    fn lower_bound<T: Ord>(&self, value: &T) -> usize
+    where
+        L: ListMut<T>,
+    {
+        self.inner
+            .binary_search_by(|x| match x.cmp(value) {
+                Ordering::Less => Ordering::Less,
+                _ => Ordering::Greater,
+            })
+            .unwrap_or_else(|i| i)
+    }
+
+    /// Index of the first element greater than `value`.
+    fn upper_bound<T: Ord>(&self, value: &T) -> usize
+    where
+        L: ListMut<T>,
+    {
+        self.inner
+            .binary_search_by(|x| match x.cmp(value) {
+                Ordering::Greater => Ordering::Greater,
+                _ => Ordering::Less,
+            })
+            .unwrap_or_else(|i| i)
+    }
+
+    /// Inserts `value` at its sorted position and returns that position.
+    ///
+    /// Equal elements keep their insertion order. If `UNIQUE`, an existing
+    /// equal element is replaced instead.
+    pub fn insert_sorted<T: Ord>(&mut self, value: T) -> Result<usize, Error<T>>
+    where
+        L: ListMut<T>,
+    {
+        if UNIQUE {
+            if let Some(index) = self.find_index(&value) {
+                if let Some(mut slot) = self.inner.get_mut(index) {
+                    *slot = value;
+                }
+                return Ok(index);
+            }
+        }
+        let index = self.upper_bound(&value);
+        self.inner.insert(index, value)?;
+        Ok(index)
+    }
+
+    /// Removes one element equal to `value`, if any.
+    pub fn remove_value<T: Ord>(&mut self, value: &T) -> Option<T>
+    where
+        L: ListMut<T>,
+    {
+        let index = self.find_index(value)?;
+        Some(self.inner.remove(index))
+    }
+
+    /// Iterates over the elements that fall within `range`, in order.
+    pub fn range<'a, T: Ord + 'a, R: RangeBounds<T>>(
+        &'a self,
+        range: R,
+    ) -> impl Iterator<Item = &'a T> + 'a
+    where
+        L: ListMut<T>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(x) => self.lower_bound(x),
+            Bound::Excluded(x) => self.upper_bound(x),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(x) => self.upper_bound(x),
+            Bound::Excluded(x) => self.lower_bound(x),
+            Bound::Unbounded => self.inner.len(),
+        };
+        (start..end.max(start)).filter_map(move |i| self.inner.get(i))
+    }
+
+    /// Number of elements strictly less than `value`.
+    pub fn rank<T: Ord>(&self, value: &T) -> usize
+    where
+        L: ListMut<T>,
+    {
+        self.lower_bound(value)
+    }
+
+    /// The `n`th smallest element, counting from zero.
+    pub fn select<T: Ord>(&self, n: usize) -> Option<&T>
+    where
+        L: ListMut<T>,
+    {
+        self.inner.get(n)
+    }
+
+    pub fn pop_first<T: Ord>(&mut self) -> Option<T>
+    where
+        L: ListMut<T>,
+    {
+        if self.inner.is_empty() {
+            None
+        } else {
+            Some(self.inner.remove(0))
+        }
+    }
+
+    pub fn pop_last<T: Ord>(&mut self) -> Option<T>
+    where
+        L: ListMut<T>,
+    {
+        self.inner.pop()
+    }
+}
+
+impl<L: Iterable, const UNIQUE: bool> Iterable for SortedList<L, UNIQUE> {
+    type Item<'collection>
+        = L::Item<'collection>
+    where
+        Self: 'collection;
+    type Iterator<'collection>
+        = L::Iterator<'collection>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.inner.iter()
+    }
+}
+
+impl<T: Ord, L: ListMut<T>, const UNIQUE: bool> Collection<T> for SortedList<L, UNIQUE> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.binary_search(other).is_ok()
+    }
+}
+
+impl<T: Ord, L: ListMut<T>, const UNIQUE: bool> CollectionMut<T> for SortedList<L, UNIQUE> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<T: Ord, L: ListMut<T>, const UNIQUE: bool> List<T> for SortedList<L, UNIQUE> {
+    type Slice<'a>
+        = L::Slice<'a>
+    where
+        T: 'a,
+        Self: 'a;
+
+    #[inline(always)]
+    fn as_slice(&self) -> Self::Slice<'_> {
+        self.inner.as_slice()
+    }
+
+    fn find_index(&self, other: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let index = self.lower_bound(other);
+        match self.inner.get(index) {
+            Some(x) if x == other => Some(index),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Option<&T> {
+        self.inner.first()
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Option<&T> {
+        self.inner.last()
+    }
+
+    #[inline(always)]
+    fn get(&self, index: usize) -> Option<&T> {
+        self.inner.get(index)
+    }
+
+    #[inline(always)]
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.inner.binary_search(x)
+    }
+
+    #[inline(always)]
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.inner.binary_search_by(f)
+    }
+
+    #[inline(always)]
+    fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.inner.binary_search_by_key(b, f)
+    }
+
+    #[inline(always)]
+    fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.starts_with(needle)
+    }
+
+    #[inline(always)]
+    fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.ends_with(needle)
+    }
+}
+
+impl<T: Ord, L: ListMut<T>> Set<T> for SortedList<L, true> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn get(&self, value: &T) -> Option<&T> {
+        self.find_index(value).and_then(|i| self.inner.get(i))
+    }
+}
+
+impl<T: Ord, L: ListMut<T>> SetMut<T> for SortedList<L, true> {
+    /// # Panics
+    ///
+    /// Panics if the underlying list is full. Use [`SetMut::try_insert`] to
+    /// handle that case.
+    fn insert(&mut self, value: T) -> bool {
+        match self.try_insert(value) {
+            Ok(inserted) => inserted,
+            Err(_) => panic!("SortedSet capacity exceeded"),
+        }
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, value: &T) -> bool {
+        self.remove_value(value).is_some()
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn try_insert(&mut self, value: T) -> Result<bool, Error<T>> {
+        let index = self.lower_bound(&value);
+        if self.inner.get(index) == Some(&value) {
+            return Ok(false);
+        }
+        self.inner.insert(index, value)?;
+        Ok(true)
+    }
+}
@@ -1,11 +1,16 @@
 use alloc::vec::Vec;
 
-use super::{List, ListMut, ListResizable, ListSortable};
+use core::ops::RangeBounds;
+
+use super::{
+    List, ListDrain, ListMut, ListNew, ListResizable, ListSliceMut, ListSortable,
+};
 use crate::{Collection, CollectionMut, Error, Iterable, IterableMut};
 
 mod inner_vec {
-    use alloc::vec::Vec;
+    use alloc::vec::{Drain, Vec};
     use core::{
+        ops::RangeBounds,
         panic::AssertUnwindSafe,
         slice::{Iter, IterMut},
     };
@@ -13,6 +18,7 @@ mod inner_vec {
     #[cfg(feature = "std")]
     use std::panic::catch_unwind;
 
+    use crate::list::{to_range, ExtractIf};
     use crate::Error;
 
     #[cfg(not(feature = "std"))]
@@ -317,6 +323,57 @@ mod inner_vec {
     pub(crate) fn split_off<T>(vec: &mut Vec<T>, at: usize) -> Vec<T> {
         vec.split_off(at)
     }
+
+    #[inline(always)]
+    pub(crate) fn as_mut_slice<T>(vec: &mut Vec<T>) -> &mut [T] {
+        vec.as_mut_slice()
+    }
+
+    #[inline(always)]
+    pub(crate) fn drain<T, R>(vec: &mut Vec<T>, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        vec.drain(range)
+    }
+
+    /// Inserts the replacement behind the range up front, then drains the
+    /// range, so the list is final as soon as this returns.
+    pub(crate) fn splice<T, R, I>(vec: &mut Vec<T>, range: R, replace_with: I) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let range = to_range(range, vec.len());
+        drop(vec.splice(range.end..range.end, replace_with));
+        vec.drain(range)
+    }
+
+    #[inline(always)]
+    pub(crate) fn extract_if<T, R, F>(
+        vec: &mut Vec<T>,
+        range: R,
+        filter: F,
+    ) -> ExtractIf<'_, T, Vec<T>, F>
+    where
+        R: RangeBounds<usize>,
+    {
+        // SAFETY: these are `Vec`'s own pointer and length accessors.
+        unsafe { ExtractIf::new(vec, range, filter, Vec::as_mut_ptr, Vec::set_len) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn extend_from_slice<T: Clone>(vec: &mut Vec<T>, other: &[T]) {
+        vec.extend_from_slice(other)
+    }
+
+    #[inline(always)]
+    pub(crate) fn extend_from_within<T: Clone, R>(vec: &mut Vec<T>, src: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        vec.extend_from_within(src)
+    }
 }
 
 impl<T> Iterable for Vec<T> {
@@ -445,7 +502,7 @@ impl<T: PartialEq + core::fmt::Debug> List<T> for Vec<T> {
     }
 }
 
-impl<T: PartialEq + core::fmt::Debug> ListMut<T> for Vec<T> {
+impl<T: PartialEq + core::fmt::Debug> ListNew<T> for Vec<T> {
     #[inline(always)]
     fn new() -> Self
     where
@@ -453,6 +510,14 @@ impl<T: PartialEq + core::fmt::Debug> ListMut<T> for Vec<T> {
     {
         Vec::new()
     }
+}
+
+impl<T: PartialEq + core::fmt::Debug> ListMut<T> for Vec<T> {
+    type ElementMut<'a>
+        = &'a mut T
+    where
+        T: 'a,
+        Self: 'a;
 
     #[inline(always)]
     fn capacity(&self) -> usize {
@@ -469,17 +534,17 @@ impl<T: PartialEq + core::fmt::Debug> ListMut<T> for Vec<T> {
     }
 
     #[inline(always)]
-    fn first_mut(&mut self) -> Option<&mut T> {
+    fn first_mut(&mut self) -> Option<Self::ElementMut<'_>> {
         inner_vec::first_mut(self)
     }
 
     #[inline(always)]
-    fn last_mut(&mut self) -> Option<&mut T> {
+    fn last_mut(&mut self) -> Option<Self::ElementMut<'_>> {
         inner_vec::last_mut(self)
     }
 
     #[inline(always)]
-    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+    fn get_mut(&mut self, index: usize) -> Option<Self::ElementMut<'_>> {
         inner_vec::get_mut(self, index)
     }
 
@@ -661,3 +726,77 @@ impl<T: PartialEq + core::fmt::Debug> ListSortable<T> for Vec<T> {
         inner_vec::sort_unstable_by_key(self, f)
     }
 }
+
+impl<T: PartialEq + core::fmt::Debug> ListDrain<T> for Vec<T> {
+    type Drain<'a>
+        = alloc::vec::Drain<'a, T>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type Splice<'a>
+        = alloc::vec::Drain<'a, T>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type ExtractIf<'a, F>
+        = crate::list::ExtractIf<'a, T, Self, F>
+    where
+        T: 'a,
+        Self: 'a,
+        F: FnMut(&mut T) -> bool;
+
+    #[inline(always)]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        inner_vec::drain(self, range)
+    }
+
+    #[inline(always)]
+    fn splice<R, I>(&mut self, range: R, replace_with: I) -> Result<Self::Splice<'_>, Error<T>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        Ok(inner_vec::splice(self, range, replace_with))
+    }
+
+    #[inline(always)]
+    fn extract_if<R, F>(&mut self, range: R, filter: F) -> Self::ExtractIf<'_, F>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool,
+    {
+        inner_vec::extract_if(self, range, filter)
+    }
+
+    #[inline(always)]
+    fn extend_from_slice(&mut self, other: &[T]) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        inner_vec::extend_from_slice(self, other);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn extend_from_within<R>(&mut self, src: R) -> Result<(), Error<T>>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        inner_vec::extend_from_within(self, src);
+        Ok(())
+    }
+}
+
+impl<T> ListSliceMut<T> for Vec<T> {
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        inner_vec::as_mut_slice(self)
+    }
+}
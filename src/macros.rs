@@ -0,0 +1,150 @@
+use crate::{Error, ListNew, MapMut, SetMut};
+
+/// Builds a list of the given type from the given elements, pushing them in
+/// order onto [`ListNew::new`].
+///
+/// Returns the element that did not fit in [`Error::InsertFailed`] if the
+/// list runs out of room. See [`list!`] for a form that panics instead.
+#[macro_export]
+macro_rules! try_list {
+    ($list:ty $(;)?) => {
+        $crate::__try_list::<_, $list, 0>([])
+    };
+    ($list:ty; $($item:expr),+ $(,)?) => {
+        $crate::__try_list::<_, $list, _>([$($item),+])
+    };
+}
+
+/// Builds a list of the given type from the given elements, like [`try_list!`].
+///
+/// # Panics
+///
+/// Panics if the list runs out of room.
+#[macro_export]
+macro_rules! list {
+    ($list:ty $(;)?) => {
+        $crate::__list::<_, $list, 0>([])
+    };
+    ($list:ty; $($item:expr),+ $(,)?) => {
+        $crate::__list::<_, $list, _>([$($item),+])
+    };
+}
+
+/// Builds a map of the given type from `key => value` pairs, inserting them in
+/// order into its `Default` value. Later pairs replace earlier ones with the
+/// same key.
+///
+/// Returns the pair that did not fit in [`Error::InsertFailed`] if the map
+/// runs out of room. See [`map!`] for a form that panics instead.
+#[macro_export]
+macro_rules! try_map {
+    ($map:ty $(;)?) => {
+        $crate::__try_map::<_, _, $map, 0>([])
+    };
+    ($map:ty; $($key:expr => $value:expr),+ $(,)?) => {
+        $crate::__try_map::<_, _, $map, _>([$(($key, $value)),+])
+    };
+}
+
+/// Builds a map of the given type from `key => value` pairs, like [`try_map!`].
+///
+/// # Panics
+///
+/// Panics if the map runs out of room.
+#[macro_export]
+macro_rules! map {
+    ($map:ty $(;)?) => {
+        $crate::__map::<_, _, $map, 0>([])
+    };
+    ($map:ty; $($key:expr => $value:expr),+ $(,)?) => {
+        $crate::__map::<_, _, $map, _>([$(($key, $value)),+])
+    };
+}
+
+/// Builds a set of the given type from the given values, inserting them in order
+/// into its `Default` value.
+///
+/// Returns the value that did not fit in [`Error::InsertFailed`] if the set
+/// runs out of room. See [`set!`] for a form that panics instead.
+#[macro_export]
+macro_rules! try_set {
+    ($set:ty $(;)?) => {
+        $crate::__try_set::<_, $set, 0>([])
+    };
+    ($set:ty; $($value:expr),+ $(,)?) => {
+        $crate::__try_set::<_, $set, _>([$($value),+])
+    };
+}
+
+/// Builds a set of the given type from the given values, like [`try_set!`].
+///
+/// # Panics
+///
+/// Panics if the set runs out of room.
+#[macro_export]
+macro_rules! set {
+    ($set:ty $(;)?) => {
+        $crate::__set::<_, $set, 0>([])
+    };
+    ($set:ty; $($value:expr),+ $(,)?) => {
+        $crate::__set::<_, $set, _>([$($value),+])
+    };
+}
+
+// The macros expand to calls of these rather than to inline loops, so the
+// element type is inferred from the collection and each expansion stays
+// small. Arrays keep them free of any allocation of their own.
+
+#[doc(hidden)]
+pub fn __try_list<T, L: ListNew<T>, const N: usize>(items: [T; N]) -> Result<L, Error<T>> {
+    let mut list = L::new();
+    for item in items {
+        list.push(item)?;
+    }
+    Ok(list)
+}
+
+#[doc(hidden)]
+pub fn __list<T, L: ListNew<T>, const N: usize>(items: [T; N]) -> L {
+    __try_list(items).unwrap_or_else(|_| panic!("list! ran out of capacity"))
+}
+
+#[doc(hidden)]
+pub fn __try_map<K, V, M, const N: usize>(entries: [(K, V); N]) -> Result<M, Error<(K, V)>>
+where
+    M: MapMut<K, V> + Default,
+{
+    let mut map = M::default();
+    for (key, value) in entries {
+        map.try_insert(key, value)?;
+    }
+    Ok(map)
+}
+
+#[doc(hidden)]
+pub fn __map<K, V, M, const N: usize>(entries: [(K, V); N]) -> M
+where
+    M: MapMut<K, V> + Default,
+{
+    __try_map(entries).unwrap_or_else(|_| panic!("map! ran out of capacity"))
+}
+
+#[doc(hidden)]
+pub fn __try_set<T, S, const N: usize>(values: [T; N]) -> Result<S, Error<T>>
+where
+    S: SetMut<T> + Default,
+{
+    let mut set = S::default();
+    for value in values {
+        set.try_insert(value)?;
+    }
+    Ok(set)
+}
+
+#[doc(hidden)]
+pub fn __set<T, S, const N: usize>(values: [T; N]) -> S
+where
+    S: SetMut<T> + Default,
+{
+    __try_set(values).unwrap_or_else(|_| panic!("set! ran out of capacity"))
+}
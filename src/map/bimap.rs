@@ -0,0 +1,187 @@
+use core::marker::PhantomData;
+
+use super::{Map, MapMut};
+use crate::{Error, Iterable};
+
+pub trait BidiMap<L, R> {
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn get_by_left(&self, left: &L) -> Option<&R>;
+    fn get_by_right(&self, right: &R) -> Option<&L>;
+
+    fn contains_left(&self, left: &L) -> bool {
+        self.get_by_left(left).is_some()
+    }
+    fn contains_right(&self, right: &R) -> bool {
+        self.get_by_right(right).is_some()
+    }
+}
+
+pub trait BidiMapMut<L, R>: BidiMap<L, R> {
+    /// Inserts the pair `(left, right)`, removing any pairs that already used
+    /// either value and reporting them.
+    fn insert(&mut self, left: L, right: R) -> Result<Overwritten<L, R>, Error<(L, R)>>;
+    fn remove_by_left(&mut self, left: &L) -> Option<(L, R)>;
+    fn remove_by_right(&mut self, right: &R) -> Option<(L, R)>;
+}
+
+/// Pairs displaced by [`BidiMapMut::insert`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Overwritten<L, R> {
+    /// Neither value was present.
+    Neither,
+    /// The left value was paired with something else, which was removed.
+    Left(L, R),
+    /// The right value was paired with something else, which was removed.
+    Right(L, R),
+    /// The exact pair was already present.
+    Pair(L, R),
+    /// Both values were paired with something else; both pairs were removed.
+    Both((L, R), (L, R)),
+}
+
+/// One-to-one map kept consistent in both directions, built from a
+/// left-to-right and a right-to-left [`MapMut`] backend.
+#[derive(Debug, Clone)]
+pub struct BiMap<L, R, LM, RM> {
+    left: LM,
+    right: RM,
+    _marker: PhantomData<(L, R)>,
+}
+
+#[cfg(feature = "std")]
+pub type HashBiMap<L, R> =
+    BiMap<L, R, std::collections::HashMap<L, R>, std::collections::HashMap<R, L>>;
+
+#[cfg(feature = "alloc")]
+pub type BTreeBiMap<L, R> =
+    BiMap<L, R, alloc::collections::BTreeMap<L, R>, alloc::collections::BTreeMap<R, L>>;
+
+impl<L, R, LM: Default, RM: Default> BiMap<L, R, LM, RM> {
+    pub fn new() -> Self {
+        Self {
+            left: LM::default(),
+            right: RM::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<L, R, LM: Default, RM: Default> Default for BiMap<L, R, LM, RM> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L, R, LM, RM> BiMap<L, R, LM, RM> {
+    /// The left-to-right direction as a [`Map`].
+    pub fn by_left(&self) -> &LM {
+        &self.left
+    }
+
+    /// The right-to-left direction as a [`Map`].
+    pub fn by_right(&self) -> &RM {
+        &self.right
+    }
+}
+
+impl<L, R, LM, RM> BiMap<L, R, LM, RM>
+where
+    L: Clone,
+    R: Clone,
+    LM: Map<L, R> + MapMut<L, R>,
+    RM: Map<R, L> + MapMut<R, L>,
+{
+    /// Puts back pairs removed by a failed insert; their slots are free, so
+    /// this cannot fail on a well-behaved backend.
+    fn restore(&mut self, pairs: [Option<(L, R)>; 2]) {
+        for (l, r) in pairs.into_iter().flatten() {
+            let _ = self.right.try_insert(r.clone(), l.clone());
+            let _ = self.left.try_insert(l, r);
+        }
+    }
+}
+
+impl<L, R, LM: Iterable, RM> Iterable for BiMap<L, R, LM, RM> {
+    type Item<'collection>
+        = LM::Item<'collection>
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = LM::Iterator<'collection>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.left.iter()
+    }
+}
+
+impl<L, R, LM: Map<L, R>, RM: Map<R, L>> BidiMap<L, R> for BiMap<L, R, LM, RM> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    #[inline(always)]
+    fn get_by_left(&self, left: &L) -> Option<&R> {
+        self.left.get(left)
+    }
+
+    #[inline(always)]
+    fn get_by_right(&self, right: &R) -> Option<&L> {
+        self.right.get(right)
+    }
+}
+
+impl<L, R, LM, RM> BidiMapMut<L, R> for BiMap<L, R, LM, RM>
+where
+    L: Clone,
+    R: Clone + PartialEq,
+    LM: Map<L, R> + MapMut<L, R>,
+    RM: Map<R, L> + MapMut<R, L>,
+{
+    fn insert(&mut self, left: L, right: R) -> Result<Overwritten<L, R>, Error<(L, R)>> {
+        let by_left = self.remove_by_left(&left);
+        let by_right = self.remove_by_right(&right);
+        let same_pair = matches!(&by_left, Some((_, r)) if *r == right);
+
+        if let Err(err) = self.left.try_insert(left.clone(), right.clone()) {
+            self.restore([by_left, by_right]);
+            return Err(err);
+        }
+        let key = left.clone();
+        if let Err(err) = self.right.try_insert(right, left) {
+            self.left.remove(&key);
+            self.restore([by_left, by_right]);
+            return Err(match err {
+                Error::InsertFailed((right, left)) => Error::InsertFailed((left, right)),
+                Error::CapacityExceeded => Error::CapacityExceeded,
+            });
+        }
+
+        Ok(match (by_left, by_right) {
+            (None, None) => Overwritten::Neither,
+            (Some((l, r)), None) if same_pair => Overwritten::Pair(l, r),
+            (Some((l, r)), None) => Overwritten::Left(l, r),
+            (None, Some((l, r))) => Overwritten::Right(l, r),
+            (Some(pair), Some(other)) => Overwritten::Both(pair, other),
+        })
+    }
+
+    fn remove_by_left(&mut self, left: &L) -> Option<(L, R)> {
+        let right = self.left.remove(left)?;
+        let left = self.right.remove(&right)?;
+        Some((left, right))
+    }
+
+    fn remove_by_right(&mut self, right: &R) -> Option<(L, R)> {
+        let left = self.right.remove(right)?;
+        let right = self.left.remove(&left)?;
+        Some((left, right))
+    }
+}
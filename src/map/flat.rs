@@ -0,0 +1,301 @@
+use core::marker::PhantomData;
+
+use super::{Map, MapMut};
+use crate::{Error, GetMut, Iterable, IterableMut, ListMut, ListNew};
+
+/// Ordered map storing its entries as sorted `(K, V)` pairs in a list.
+///
+/// Lookups are binary searches over the backing list, and iteration follows
+/// key order. Backed by `heapless::Vec` this is a fixed-capacity map that
+/// needs no allocator; backed by `Vec` it is a compact map suited to small
+/// sizes.
+#[derive(Debug, Clone)]
+pub struct FlatMap<K, V, L> {
+    inner: L,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<K: Ord, V, L: ListNew<(K, V)>> FlatMap<K, V, L> {
+    pub fn new() -> Self {
+        Self {
+            inner: L::new(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K: Ord, V, L: ListMut<(K, V)>> FlatMap<K, V, L> {
+    #[inline(always)]
+    fn search(&self, key: &K) -> Result<usize, usize> {
+        self.inner.binary_search_by(|(k, _)| k.cmp(key))
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.search(key).is_ok()
+    }
+
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.inner.first().map(|(k, v)| (k, v))
+    }
+
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.inner.last().map(|(k, v)| (k, v))
+    }
+
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> L {
+        self.inner
+    }
+}
+
+impl<K: Ord, V, L: ListNew<(K, V)>> Default for FlatMap<K, V, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the entries of a [`FlatMap`] in key order.
+pub struct Iter<'a, K, V, L> {
+    list: &'a L,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a (K, V)>,
+}
+
+impl<'a, K: 'a, V: 'a, L: ListMut<(K, V)>> Iterator for Iter<'a, K, V, L> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let (k, v) = self.list.get(self.front)?;
+        self.front += 1;
+        Some((k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, K: 'a, V: 'a, L: ListMut<(K, V)>> DoubleEndedIterator for Iter<'a, K, V, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let (k, v) = self.list.get(self.back)?;
+        Some((k, v))
+    }
+}
+
+/// Mutable iterator over the entries of a [`FlatMap`] in key order.
+pub struct IterMut<'a, K, V>(core::slice::IterMut<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (&*k, v))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for IterMut<'a, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, v)| (&*k, v))
+    }
+}
+
+/// Iterator over the keys of a [`FlatMap`] in order.
+pub struct Keys<'a, K, V, L>(Iter<'a, K, V, L>);
+
+impl<'a, K: 'a, V: 'a, L: ListMut<(K, V)>> Iterator for Keys<'a, K, V, L> {
+    type Item = &'a K;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, _)| k)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Iterator over the values of a [`FlatMap`] in key order.
+pub struct Values<'a, K, V, L>(Iter<'a, K, V, L>);
+
+impl<'a, K: 'a, V: 'a, L: ListMut<(K, V)>> Iterator for Values<'a, K, V, L> {
+    type Item = &'a V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, v)| v)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Mutable iterator over the values of a [`FlatMap`] in key order.
+pub struct ValuesMut<'a, K, V>(IterMut<'a, K, V>);
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, v)| v)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V, L: ListMut<(K, V)>> Iterable for FlatMap<K, V, L> {
+    type Item<'collection>
+        = (&'collection K, &'collection V)
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection, K, V, L>
+    where
+        Self: 'collection;
+
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        Iter {
+            list: &self.inner,
+            front: 0,
+            back: self.inner.len(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, L: ListMut<(K, V)> + AsMut<[(K, V)]>> IterableMut for FlatMap<K, V, L> {
+    type ItemMut<'collection>
+        = (&'collection K, &'collection mut V)
+    where
+        Self: 'collection;
+
+    type IteratorMut<'collection>
+        = IterMut<'collection, K, V>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        IterMut(self.inner.as_mut().iter_mut())
+    }
+}
+
+impl<K: Ord, V, L: ListMut<(K, V)>> Map<K, V> for FlatMap<K, V, L> {
+    type Keys<'key>
+        = Keys<'key, K, V, L>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = Values<'value, K, V, L>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn get(&self, k: &K) -> Option<&V> {
+        let index = self.search(k).ok()?;
+        self.inner.get(index).map(|(_, v)| v)
+    }
+
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)> {
+        let index = self.search(k).ok()?;
+        self.inner.get(index).map(|(k, v)| (k, v))
+    }
+
+    #[inline(always)]
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        Keys(self.iter())
+    }
+
+    #[inline(always)]
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        Values(self.iter())
+    }
+}
+
+impl<K: Ord, V, L: ListMut<(K, V)> + AsMut<[(K, V)]>> MapMut<K, V> for FlatMap<K, V, L> {
+    type ValuesMut<'value>
+        = ValuesMut<'value, K, V>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn values_mut<'c>(&'c mut self) -> Self::ValuesMut<'c> {
+        ValuesMut(self.iter_mut())
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the backing list is full. Use [`MapMut::try_insert`] to
+    /// handle that case.
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.try_insert(key, value) {
+            Ok(old) => old,
+            Err(_) => panic!("FlatMap capacity exceeded"),
+        }
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.search(key).ok()?;
+        Some(self.inner.remove(index).1)
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Error<(K, V)>> {
+        match self.search(&key) {
+            Ok(index) => match self.inner.as_mut().get_mut(index) {
+                Some((_, v)) => Ok(Some(core::mem::replace(v, value))),
+                None => unreachable!(),
+            },
+            Err(index) => {
+                self.inner.insert(index, (key, value))?;
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl<K: Ord, V, L: ListMut<(K, V)> + AsMut<[(K, V)]>> GetMut<K, V> for FlatMap<K, V, L> {
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        let index = self.search(k).ok()?;
+        self.inner.as_mut().get_mut(index).map(|(_, v)| v)
+    }
+}
@@ -0,0 +1,496 @@
+use core::marker::PhantomData;
+use core::ops::{Bound, Range};
+
+use super::Map;
+use crate::{Error, Iterable};
+
+/// Backing store of a [`RangeMap`]: disjoint `start..end` intervals ordered
+/// by start.
+pub trait IntervalStorage<K, V> {
+    type Range<'a>: Iterator<Item = (&'a K, &'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+        Self: 'a;
+
+    fn empty() -> Self
+    where
+        Self: Sized;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn capacity(&self) -> usize;
+    /// Intervals whose start lies within `from..`, in order.
+    fn range_from(&self, from: Bound<&K>) -> Self::Range<'_>;
+    /// The interval with the greatest start not above `point`.
+    fn floor(&self, point: &K) -> Option<(&K, &K, &V)>;
+    /// Adds an interval whose start is not yet present; the caller ensures
+    /// there is room.
+    fn insert(&mut self, start: K, end: K, value: V);
+    fn remove(&mut self, start: &K) -> Option<(K, K, V)>;
+    fn clear(&mut self);
+}
+
+#[cfg(feature = "alloc")]
+mod btree {
+    use alloc::collections::{btree_map, BTreeMap};
+    use core::ops::Bound;
+
+    use super::IntervalStorage;
+
+    type Range<'a, K, V> = core::iter::Map<
+        btree_map::Range<'a, K, (K, V)>,
+        fn((&'a K, &'a (K, V))) -> (&'a K, &'a K, &'a V),
+    >;
+
+    impl<K: Ord, V> IntervalStorage<K, V> for BTreeMap<K, (K, V)> {
+        type Range<'a>
+            = Range<'a, K, V>
+        where
+            K: 'a,
+            V: 'a,
+            Self: 'a;
+
+        #[inline(always)]
+        fn empty() -> Self {
+            BTreeMap::new()
+        }
+
+        #[inline(always)]
+        fn len(&self) -> usize {
+            BTreeMap::len(self)
+        }
+
+        #[inline(always)]
+        fn capacity(&self) -> usize {
+            usize::MAX
+        }
+
+        fn range_from(&self, from: Bound<&K>) -> Self::Range<'_> {
+            self.range::<K, _>((from, Bound::Unbounded))
+                .map(|(start, (end, value))| (start, end, value))
+        }
+
+        fn floor(&self, point: &K) -> Option<(&K, &K, &V)> {
+            self.range(..=point)
+                .next_back()
+                .map(|(start, (end, value))| (start, end, value))
+        }
+
+        #[inline(always)]
+        fn insert(&mut self, start: K, end: K, value: V) {
+            BTreeMap::insert(self, start, (end, value));
+        }
+
+        fn remove(&mut self, start: &K) -> Option<(K, K, V)> {
+            self.remove_entry(start)
+                .map(|(start, (end, value))| (start, end, value))
+        }
+
+        #[inline(always)]
+        fn clear(&mut self) {
+            BTreeMap::clear(self)
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod flat {
+    use core::ops::Bound;
+
+    use super::IntervalStorage;
+
+    type Range<'a, K, V> = core::iter::Map<
+        core::slice::Iter<'a, (K, K, V)>,
+        fn(&'a (K, K, V)) -> (&'a K, &'a K, &'a V),
+    >;
+
+    impl<K: Ord, V, const N: usize> IntervalStorage<K, V> for heapless::Vec<(K, K, V), N> {
+        type Range<'a>
+            = Range<'a, K, V>
+        where
+            K: 'a,
+            V: 'a,
+            Self: 'a;
+
+        #[inline(always)]
+        fn empty() -> Self {
+            heapless::Vec::new()
+        }
+
+        #[inline(always)]
+        fn len(&self) -> usize {
+            self.as_slice().len()
+        }
+
+        #[inline(always)]
+        fn capacity(&self) -> usize {
+            N
+        }
+
+        fn range_from(&self, from: Bound<&K>) -> Self::Range<'_> {
+            let index = match from {
+                Bound::Included(from) => self.partition_point(|(start, _, _)| start < from),
+                Bound::Excluded(from) => self.partition_point(|(start, _, _)| start <= from),
+                Bound::Unbounded => 0,
+            };
+            self[index..]
+                .iter()
+                .map(|(start, end, value)| (start, end, value))
+        }
+
+        fn floor(&self, point: &K) -> Option<(&K, &K, &V)> {
+            let index = self.partition_point(|(start, _, _)| start <= point);
+            let (start, end, value) = self.get(index.checked_sub(1)?)?;
+            Some((start, end, value))
+        }
+
+        fn insert(&mut self, start: K, end: K, value: V) {
+            let index = self.partition_point(|(s, _, _)| *s < start);
+            if heapless::Vec::insert(self, index, (start, end, value)).is_err() {
+                panic!("interval storage is full");
+            }
+        }
+
+        fn remove(&mut self, start: &K) -> Option<(K, K, V)> {
+            let index = self.binary_search_by(|(s, _, _)| s.cmp(start)).ok()?;
+            Some(heapless::Vec::remove(self, index))
+        }
+
+        #[inline(always)]
+        fn clear(&mut self) {
+            heapless::Vec::clear(self)
+        }
+    }
+}
+
+/// Map from disjoint half-open ranges of keys to values.
+///
+/// Inserting a range overwrites whatever it overlaps, trimming or splitting
+/// the existing ranges around it, and joins it with any overlapping or
+/// adjacent range holding an equal value, so each run of equal values is
+/// always stored as a single range.
+///
+/// Use [`IntervalMap`] for a map built on `BTreeMap`, or
+/// [`FlatIntervalMap`] for one holding at most `N` ranges in a sorted
+/// `heapless::Vec`. Operations that would need more ranges than a fixed map
+/// can hold fail without changing it.
+///
+/// As a [`Map`], keys are points: [`Map::get`] finds the range containing a
+/// point, and [`Map::keys`] yields the start of each range.
+#[derive(Debug, Clone)]
+pub struct RangeMap<K, V, S> {
+    storage: S,
+    _marker: PhantomData<(K, V)>,
+}
+
+/// [`RangeMap`] stored in a `BTreeMap` from start to end and value.
+#[cfg(feature = "alloc")]
+pub type IntervalMap<K, V> = RangeMap<K, V, alloc::collections::BTreeMap<K, (K, V)>>;
+
+/// [`RangeMap`] holding at most `N` ranges without an allocator.
+#[cfg(feature = "heapless")]
+pub type FlatIntervalMap<K, V, const N: usize> = RangeMap<K, V, heapless::Vec<(K, K, V), N>>;
+
+impl<K, V, S: IntervalStorage<K, V>> RangeMap<K, V, S> {
+    pub fn new() -> Self {
+        Self {
+            storage: S::empty(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Number of stored ranges.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.storage.clear();
+    }
+}
+
+impl<K, V, S: IntervalStorage<K, V>> Default for RangeMap<K, V, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Clone, V, S: IntervalStorage<K, V>> RangeMap<K, V, S> {
+    /// The value of the range containing `point`.
+    pub fn get_point(&self, point: &K) -> Option<&V> {
+        self.get_interval(point).map(|(_, value)| value)
+    }
+
+    /// The range containing `point`, with its value.
+    pub fn get_interval(&self, point: &K) -> Option<(Range<&K>, &V)> {
+        let (start, end, value) = self.storage.floor(point)?;
+        (point < end).then_some((start..end, value))
+    }
+
+    #[inline(always)]
+    pub fn contains_point(&self, point: &K) -> bool {
+        self.get_interval(point).is_some()
+    }
+
+    /// Iterates in order over the ranges sharing at least one point with
+    /// `range`.
+    pub fn overlapping(&self, range: &Range<K>) -> Overlapping<'_, K, V, S> {
+        self.search(range, false)
+    }
+
+    /// Iterates in order over the parts of `range` no stored range covers.
+    pub fn gaps(&self, range: &Range<K>) -> Gaps<'_, K, V, S> {
+        Gaps {
+            inner: self.overlapping(range),
+            cursor: (range.start < range.end).then(|| range.start.clone()),
+            end: range.end.clone(),
+        }
+    }
+
+    /// Maps every point of `range` to `value`.
+    ///
+    /// Fails with [`Error::InsertFailed`], leaving the map unchanged, if the
+    /// result would need more ranges than the storage can hold. An empty
+    /// range is ignored.
+    pub fn insert_range(&mut self, range: Range<K>, value: V) -> Result<(), Error<(Range<K>, V)>>
+    where
+        V: Clone + PartialEq,
+    {
+        if range.start >= range.end {
+            return Ok(());
+        }
+        let (mut removed, mut added) = (0, 1);
+        for (start, end, current) in self.search(&range, true) {
+            if *current == value {
+                removed += 1;
+            } else if Self::overlaps(start, end, &range) {
+                removed += 1;
+                added += usize::from(*start < range.start) + usize::from(*end > range.end);
+            }
+        }
+        if self.storage.len() - removed + added > self.storage.capacity() {
+            return Err(Error::InsertFailed((range, value)));
+        }
+
+        let (mut start, mut end) = (range.start.clone(), range.end.clone());
+        while let Some(key) = self.find_start(&range, true, |s, e, v| {
+            *v == value || Self::overlaps(s, e, &range)
+        }) {
+            let Some((s, e, v)) = self.storage.remove(&key) else {
+                break;
+            };
+            if v == value {
+                start = start.min(s);
+                end = end.max(e);
+            } else {
+                self.keep_outside(s, e, v, &range);
+            }
+        }
+        self.storage.insert(start, end, value);
+        Ok(())
+    }
+
+    /// Unmaps every point of `range`, trimming or splitting the ranges it
+    /// overlaps.
+    ///
+    /// Fails with [`Error::CapacityExceeded`], leaving the map unchanged, if
+    /// splitting a range would need more room than the storage has.
+    pub fn remove_range(&mut self, range: Range<K>) -> Result<(), Error<Range<K>>>
+    where
+        V: Clone,
+    {
+        if range.start >= range.end {
+            return Ok(());
+        }
+        let (mut removed, mut added) = (0, 0);
+        for (start, end, _) in self.search(&range, false) {
+            removed += 1;
+            added += usize::from(*start < range.start) + usize::from(*end > range.end);
+        }
+        if self.storage.len() - removed + added > self.storage.capacity() {
+            return Err(Error::CapacityExceeded);
+        }
+
+        while let Some(key) = self.find_start(&range, false, |_, _, _| true) {
+            let Some((s, e, v)) = self.storage.remove(&key) else {
+                break;
+            };
+            self.keep_outside(s, e, v, &range);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn overlaps(start: &K, end: &K, range: &Range<K>) -> bool {
+        *start < range.end && range.start < *end
+    }
+
+    /// Stores back the parts of `start..end` lying outside `range`.
+    fn keep_outside(&mut self, start: K, end: K, value: V, range: &Range<K>)
+    where
+        V: Clone,
+    {
+        match (start < range.start, end > range.end) {
+            (true, true) => {
+                self.storage
+                    .insert(start, range.start.clone(), value.clone());
+                self.storage.insert(range.end.clone(), end, value);
+            }
+            (true, false) => self.storage.insert(start, range.start.clone(), value),
+            (false, true) => self.storage.insert(range.end.clone(), end, value),
+            (false, false) => {}
+        }
+    }
+
+    /// Start of the first range found by [`Self::search`] that matches
+    /// `predicate`.
+    fn find_start<F>(&self, range: &Range<K>, touching: bool, mut predicate: F) -> Option<K>
+    where
+        F: FnMut(&K, &K, &V) -> bool,
+    {
+        self.search(range, touching)
+            .find(|(start, end, value)| predicate(start, end, value))
+            .map(|(start, _, _)| start.clone())
+    }
+
+    /// Ranges overlapping `range`, or also just touching it if `touching`.
+    fn search(&self, range: &Range<K>, touching: bool) -> Overlapping<'_, K, V, S> {
+        let from = match self.storage.floor(&range.start) {
+            Some((start, end, _)) if *end > range.start || (touching && *end == range.start) => {
+                Bound::Included(start)
+            }
+            _ => Bound::Excluded(&range.start),
+        };
+        Overlapping {
+            inner: self.storage.range_from(from),
+            end: range.end.clone(),
+            touching,
+        }
+    }
+}
+
+/// Iterator over the ranges of a [`RangeMap`] overlapping a given range.
+pub struct Overlapping<'a, K: 'a, V: 'a, S: IntervalStorage<K, V> + 'a> {
+    inner: S::Range<'a>,
+    end: K,
+    touching: bool,
+}
+
+impl<'a, K: Ord + 'a, V: 'a, S: IntervalStorage<K, V>> Iterator for Overlapping<'a, K, V, S> {
+    type Item = (&'a K, &'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end, value) = self.inner.next()?;
+        (*start < self.end || (self.touching && *start == self.end)).then_some((start, end, value))
+    }
+}
+
+/// Iterator over the uncovered parts of a range in a [`RangeMap`].
+pub struct Gaps<'a, K: 'a, V: 'a, S: IntervalStorage<K, V> + 'a> {
+    inner: Overlapping<'a, K, V, S>,
+    cursor: Option<K>,
+    end: K,
+}
+
+impl<'a, K: Ord + Clone + 'a, V: 'a, S: IntervalStorage<K, V>> Iterator for Gaps<'a, K, V, S> {
+    type Item = Range<K>;
+
+    fn next(&mut self) -> Option<Range<K>> {
+        let mut cursor = self.cursor.take()?;
+        loop {
+            match self.inner.next() {
+                Some((start, end, _)) if *start > cursor => {
+                    self.cursor = Some(end.clone());
+                    return Some(cursor..start.clone());
+                }
+                Some((_, end, _)) => {
+                    if *end > cursor {
+                        cursor = end.clone();
+                    }
+                }
+                None => return (cursor < self.end).then(|| cursor..self.end.clone()),
+            }
+        }
+    }
+}
+
+type Iter<'a, K, V, S> = core::iter::Map<
+    <S as IntervalStorage<K, V>>::Range<'a>,
+    fn((&'a K, &'a K, &'a V)) -> (Range<&'a K>, &'a V),
+>;
+type Keys<'a, K, V, S> =
+    core::iter::Map<<S as IntervalStorage<K, V>>::Range<'a>, fn((&'a K, &'a K, &'a V)) -> &'a K>;
+type Values<'a, K, V, S> =
+    core::iter::Map<<S as IntervalStorage<K, V>>::Range<'a>, fn((&'a K, &'a K, &'a V)) -> &'a V>;
+
+impl<K, V, S: IntervalStorage<K, V>> Iterable for RangeMap<K, V, S> {
+    type Item<'collection>
+        = (Range<&'collection K>, &'collection V)
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection, K, V, S>
+    where
+        Self: 'collection;
+
+    /// Iterates over the ranges in order.
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.storage
+            .range_from(Bound::Unbounded)
+            .map(|(start, end, value)| (start..end, value))
+    }
+}
+
+impl<K: Ord + Clone, V, S: IntervalStorage<K, V>> Map<K, V> for RangeMap<K, V, S> {
+    type Keys<'key>
+        = Keys<'key, K, V, S>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = Values<'value, K, V, S>
+    where
+        V: 'value,
+        Self: 'value;
+
+    /// Number of stored ranges.
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// The value of the range containing the point `k`.
+    #[inline(always)]
+    fn get(&self, k: &K) -> Option<&V> {
+        self.get_point(k)
+    }
+
+    /// The starts of the ranges, in order.
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        self.storage
+            .range_from(Bound::Unbounded)
+            .map(|(start, _, _)| start)
+    }
+
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        self.storage
+            .range_from(Bound::Unbounded)
+            .map(|(_, _, value)| value)
+    }
+
+    /// The start and value of the range containing the point `k`.
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)> {
+        self.get_interval(k)
+            .map(|(range, value)| (range.start, value))
+    }
+}
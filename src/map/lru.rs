@@ -0,0 +1,525 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use super::{Map, MapMut};
+use crate::{GetMut, Iterable, IterableMut};
+
+const NIL: usize = usize::MAX;
+
+/// Storage slot of an [`Lru`] cache, linked into the recency list by index.
+#[derive(Debug, Clone)]
+pub struct LruSlot<K, V> {
+    entry: Option<(K, V)>,
+    prev: usize,
+    next: usize,
+}
+
+impl<K, V> LruSlot<K, V> {
+    fn empty() -> Self {
+        Self {
+            entry: None,
+            prev: NIL,
+            next: NIL,
+        }
+    }
+}
+
+/// How an [`Lru`] cache finds the slot holding a key.
+pub trait LruIndex<K> {
+    fn find<V>(&self, slots: &[LruSlot<K, V>], key: &K) -> Option<usize>;
+    fn inserted(&mut self, key: &K, slot: usize);
+    fn removed(&mut self, key: &K);
+}
+
+/// Index that scans every slot, for small fixed-size caches.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ScanIndex;
+
+impl<K: PartialEq> LruIndex<K> for ScanIndex {
+    fn find<V>(&self, slots: &[LruSlot<K, V>], key: &K) -> Option<usize> {
+        slots
+            .iter()
+            .position(|slot| matches!(&slot.entry, Some((k, _)) if k == key))
+    }
+
+    #[inline(always)]
+    fn inserted(&mut self, _key: &K, _slot: usize) {}
+
+    #[inline(always)]
+    fn removed(&mut self, _key: &K) {}
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord + Clone> LruIndex<K> for alloc::collections::BTreeMap<K, usize> {
+    #[inline(always)]
+    fn find<V>(&self, _slots: &[LruSlot<K, V>], key: &K) -> Option<usize> {
+        self.get(key).copied()
+    }
+
+    #[inline(always)]
+    fn inserted(&mut self, key: &K, slot: usize) {
+        self.insert(key.clone(), slot);
+    }
+
+    #[inline(always)]
+    fn removed(&mut self, key: &K) {
+        self.remove(key);
+    }
+}
+
+/// Least-recently-used cache that evicts its oldest entry once full.
+///
+/// Entries live in a fixed set of slots threaded onto an index-linked recency
+/// list. [`Lru::get`], [`Lru::touch`] and [`GetMut::get_mut`] mark the entry
+/// as most recently used; [`Lru::peek`] and [`Map::get`] leave the list
+/// alone. Method calls only reach the inherent `get` while [`Map`] is not in
+/// scope, since `Map::get` borrows immutably and is found first; with it
+/// imported, call `Lru::get(&mut cache, key)` or `touch` instead.
+///
+/// Use [`LruMap`] for a heap-allocated cache with a runtime capacity, or
+/// [`ArrayLruMap`] for an array-backed one that needs no allocator.
+#[derive(Clone)]
+pub struct Lru<K, V, S, I, E> {
+    slots: S,
+    index: I,
+    head: usize,
+    tail: usize,
+    free: usize,
+    len: usize,
+    on_evict: Option<E>,
+    _marker: PhantomData<(K, V)>,
+}
+
+/// Heap-allocated [`Lru`] cache with a capacity chosen at runtime.
+#[cfg(feature = "alloc")]
+pub type LruMap<K, V, E = fn(&K, &V)> = Lru<
+    K,
+    V,
+    alloc::boxed::Box<[LruSlot<K, V>]>,
+    alloc::collections::BTreeMap<K, usize>,
+    E,
+>;
+
+/// Array-backed [`Lru`] cache holding at most `N` entries.
+pub type ArrayLruMap<K, V, const N: usize, E = fn(&K, &V)> =
+    Lru<K, V, [LruSlot<K, V>; N], ScanIndex, E>;
+
+#[cfg(feature = "alloc")]
+impl<K: Ord + Clone, V> LruMap<K, V> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hook_inner(capacity, None)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord + Clone, V, E: FnMut(&K, &V)> LruMap<K, V, E> {
+    /// Creates a cache that calls `on_evict` for every entry pushed out to
+    /// make room for a new one.
+    pub fn with_capacity_and_hook(capacity: usize, on_evict: E) -> Self {
+        Self::with_capacity_and_hook_inner(capacity, Some(on_evict))
+    }
+
+    fn with_capacity_and_hook_inner(capacity: usize, on_evict: Option<E>) -> Self {
+        let slots = (0..capacity).map(|_| LruSlot::empty()).collect();
+        Self::from_slots(slots, alloc::collections::BTreeMap::new(), on_evict)
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> ArrayLruMap<K, V, N> {
+    pub fn new() -> Self {
+        Self::from_slots([(); N].map(|_| LruSlot::empty()), ScanIndex, None)
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> Default for ArrayLruMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PartialEq, V, const N: usize, E: FnMut(&K, &V)> ArrayLruMap<K, V, N, E> {
+    /// Creates a cache that calls `on_evict` for every entry pushed out to
+    /// make room for a new one.
+    pub fn with_hook(on_evict: E) -> Self {
+        Self::from_slots([(); N].map(|_| LruSlot::empty()), ScanIndex, Some(on_evict))
+    }
+}
+
+impl<K, V, S, I, E> Lru<K, V, S, I, E>
+where
+    S: AsRef<[LruSlot<K, V>]> + AsMut<[LruSlot<K, V>]>,
+    I: LruIndex<K>,
+{
+    fn from_slots(mut slots: S, index: I, on_evict: Option<E>) -> Self {
+        let capacity = slots.as_ref().len();
+        for (i, slot) in slots.as_mut().iter_mut().enumerate() {
+            slot.next = if i + 1 < capacity { i + 1 } else { NIL };
+        }
+        Self {
+            slots,
+            index,
+            head: NIL,
+            tail: NIL,
+            free: if capacity > 0 { 0 } else { NIL },
+            len: 0,
+            on_evict,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Maximum number of entries the cache holds before evicting.
+    pub fn capacity(&self) -> usize {
+        self.slots.as_ref().len()
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Returns the value for `key` without marking it as recently used, the
+    /// same as [`Map::get`].
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        let index = self.find(key)?;
+        self.entry(index).map(|(_, v)| v)
+    }
+
+    /// Returns the value for `key` and marks it as most recently used, the
+    /// same as [`Lru::touch`].
+    #[inline(always)]
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.touch(key)
+    }
+
+    /// Returns the value for `key` and marks it as most recently used.
+    pub fn touch(&mut self, key: &K) -> Option<&V> {
+        let index = self.find(key)?;
+        self.move_to_front(index);
+        self.entry(index).map(|(_, v)| v)
+    }
+
+    /// The least recently used entry, which is the next to be evicted.
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.entry(self.tail).map(|(k, v)| (k, v))
+    }
+
+    /// Inserts a pair and marks it as most recently used.
+    ///
+    /// Returns the previous pair if `key` was already present, or else the
+    /// least recently used pair if it had to be evicted to make room.
+    pub fn push(&mut self, key: K, value: V) -> Option<(K, V)>
+    where
+        E: FnMut(&K, &V),
+    {
+        if let Some(index) = self.find(&key) {
+            self.move_to_front(index);
+            let slot = &mut self.slots.as_mut()[index];
+            let (_, old) = slot.entry.as_mut()?;
+            return Some((key, core::mem::replace(old, value)));
+        }
+        self.push_new(key, value)
+    }
+
+    /// Removes and returns the least recently used entry.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let tail = self.tail;
+        if tail == NIL {
+            return None;
+        }
+        Some(self.remove_at(tail))
+    }
+
+    #[inline(always)]
+    fn find(&self, key: &K) -> Option<usize> {
+        self.index.find(self.slots.as_ref(), key)
+    }
+
+    #[inline(always)]
+    fn entry(&self, index: usize) -> Option<&(K, V)> {
+        self.slots.as_ref().get(index)?.entry.as_ref()
+    }
+
+    fn detach(&mut self, index: usize) {
+        let slots = self.slots.as_mut();
+        let prev = slots[index].prev;
+        let next = slots[index].next;
+        match prev {
+            NIL => self.head = next,
+            prev => slots[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => slots[next].prev = prev,
+        }
+    }
+
+    fn attach_front(&mut self, index: usize) {
+        let slots = self.slots.as_mut();
+        let head = self.head;
+        slots[index].prev = NIL;
+        slots[index].next = head;
+        match head {
+            NIL => self.tail = index,
+            head => slots[head].prev = index,
+        }
+        self.head = index;
+    }
+
+    /// Marks the entry at `index` as most recently used.
+    fn move_to_front(&mut self, index: usize) {
+        if self.head != index {
+            self.detach(index);
+            self.attach_front(index);
+        }
+    }
+
+    fn remove_at(&mut self, index: usize) -> (K, V) {
+        self.detach(index);
+        let slot = &mut self.slots.as_mut()[index];
+        let entry = slot.entry.take().expect("linked slot holds an entry");
+        slot.next = self.free;
+        self.free = index;
+        self.len -= 1;
+        self.index.removed(&entry.0);
+        entry
+    }
+
+    /// Inserts a key known to be absent, evicting if the cache is full.
+    fn push_new(&mut self, key: K, value: V) -> Option<(K, V)>
+    where
+        E: FnMut(&K, &V),
+    {
+        if self.capacity() == 0 {
+            if let Some(on_evict) = &mut self.on_evict {
+                on_evict(&key, &value);
+            }
+            return Some((key, value));
+        }
+
+        let evicted = if self.free == NIL {
+            let evicted = self.remove_at(self.tail);
+            if let Some(on_evict) = &mut self.on_evict {
+                on_evict(&evicted.0, &evicted.1);
+            }
+            Some(evicted)
+        } else {
+            None
+        };
+
+        let index = self.free;
+        let slot = &mut self.slots.as_mut()[index];
+        self.free = slot.next;
+        slot.entry = Some((key, value));
+        self.len += 1;
+        self.attach_front(index);
+        if let Some((key, _)) = &self.slots.as_ref()[index].entry {
+            self.index.inserted(key, index);
+        }
+        evicted
+    }
+}
+
+impl<K, V, S, I, E> fmt::Debug for Lru<K, V, S, I, E>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+    S: AsRef<[LruSlot<K, V>]>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over the entries of an [`Lru`], most recently used first.
+pub struct Iter<'a, K, V> {
+    slots: &'a [LruSlot<K, V>],
+    next: usize,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let slot = self.slots.get(self.next)?;
+        self.next = slot.next;
+        self.remaining -= 1;
+        slot.entry.as_ref().map(|(k, v)| (k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Mutable iterator over the entries of an [`Lru`], most recently used first.
+pub struct IterMut<'a, K, V> {
+    slots: *mut LruSlot<K, V>,
+    next: usize,
+    remaining: usize,
+    _marker: PhantomData<&'a mut LruSlot<K, V>>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `next` always names a linked slot within the borrowed slice,
+        // and the recency list visits each slot at most once.
+        let slot = unsafe { &mut *self.slots.add(self.next) };
+        self.next = slot.next;
+        self.remaining -= 1;
+        slot.entry.as_mut().map(|(k, v)| (&*k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+type Keys<'a, K, V> = core::iter::Map<Iter<'a, K, V>, fn((&'a K, &'a V)) -> &'a K>;
+type Values<'a, K, V> = core::iter::Map<Iter<'a, K, V>, fn((&'a K, &'a V)) -> &'a V>;
+type ValuesMut<'a, K, V> = core::iter::Map<IterMut<'a, K, V>, fn((&'a K, &'a mut V)) -> &'a mut V>;
+
+impl<K, V, S, I, E> Iterable for Lru<K, V, S, I, E>
+where
+    S: AsRef<[LruSlot<K, V>]>,
+{
+    type Item<'collection>
+        = (&'collection K, &'collection V)
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection, K, V>
+    where
+        Self: 'collection;
+
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        Iter {
+            slots: self.slots.as_ref(),
+            next: self.head,
+            remaining: self.len,
+        }
+    }
+}
+
+impl<K, V, S, I, E> IterableMut for Lru<K, V, S, I, E>
+where
+    S: AsMut<[LruSlot<K, V>]>,
+{
+    type ItemMut<'collection>
+        = (&'collection K, &'collection mut V)
+    where
+        Self: 'collection;
+
+    type IteratorMut<'collection>
+        = IterMut<'collection, K, V>
+    where
+        Self: 'collection;
+
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        IterMut {
+            slots: self.slots.as_mut().as_mut_ptr(),
+            next: self.head,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, S, I, E> Map<K, V> for Lru<K, V, S, I, E>
+where
+    S: AsRef<[LruSlot<K, V>]> + AsMut<[LruSlot<K, V>]>,
+    I: LruIndex<K>,
+{
+    type Keys<'key>
+        = Keys<'key, K, V>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = Values<'value, K, V>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the value for `k` without marking it as recently used.
+    #[inline(always)]
+    fn get(&self, k: &K) -> Option<&V> {
+        self.peek(k)
+    }
+
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        self.iter().map(|(_, v)| v)
+    }
+}
+
+impl<K, V, S, I, E> MapMut<K, V> for Lru<K, V, S, I, E>
+where
+    S: AsRef<[LruSlot<K, V>]> + AsMut<[LruSlot<K, V>]>,
+    I: LruIndex<K>,
+    E: FnMut(&K, &V),
+{
+    type ValuesMut<'value>
+        = ValuesMut<'value, K, V>
+    where
+        V: 'value,
+        Self: 'value;
+
+    fn values_mut<'c>(&'c mut self) -> Self::ValuesMut<'c> {
+        self.iter_mut().map(|(_, v)| v)
+    }
+
+    /// Inserts a pair, evicting the least recently used entry if the cache is
+    /// full. Use [`Lru::push`] to get hold of the evicted entry.
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.find(&key) {
+            Some(_) => self.push(key, value).map(|(_, v)| v),
+            None => {
+                self.push_new(key, value);
+                None
+            }
+        }
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.find(key)?;
+        Some(self.remove_at(index).1)
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        Lru::capacity(self)
+    }
+}
+
+impl<K, V, S, I, E> GetMut<K, V> for Lru<K, V, S, I, E>
+where
+    S: AsRef<[LruSlot<K, V>]> + AsMut<[LruSlot<K, V>]>,
+    I: LruIndex<K>,
+    E: FnMut(&K, &V),
+{
+    /// Returns the value for `k` and marks it as most recently used.
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        let index = self.find(k)?;
+        self.move_to_front(index);
+        let (_, v) = self.slots.as_mut()[index].entry.as_mut()?;
+        Some(v)
+    }
+}
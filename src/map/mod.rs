@@ -1,4 +1,40 @@
-use crate::{Iterable, IterableMut};
+use crate::{Error, Iterable, IterableMut};
+
+mod bimap;
+mod flat;
+mod interval;
+mod lru;
+#[cfg(feature = "alloc")]
+mod ordered;
+#[cfg(feature = "alloc")]
+mod persistent;
+mod slot;
+mod trie;
+
+#[cfg(feature = "alloc")]
+pub use bimap::BTreeBiMap;
+#[cfg(feature = "std")]
+pub use bimap::HashBiMap;
+pub use bimap::{BiMap, BidiMap, BidiMapMut, Overwritten};
+pub use flat::FlatMap;
+#[cfg(feature = "heapless")]
+pub use interval::FlatIntervalMap;
+#[cfg(feature = "alloc")]
+pub use interval::IntervalMap;
+pub use interval::{Gaps, IntervalStorage, Overlapping, RangeMap};
+#[cfg(feature = "alloc")]
+pub use lru::LruMap;
+pub use lru::{ArrayLruMap, Lru, LruIndex, LruSlot, ScanIndex};
+#[cfg(feature = "alloc")]
+pub use ordered::{FnvBuildHasher, FnvHasher, OrderedHashMap};
+#[cfg(feature = "alloc")]
+pub use persistent::PMap;
+#[cfg(feature = "alloc")]
+pub use slot::SlotMap;
+pub use slot::{ArraySlotMap, Key, Slot, SlotArena, SlotStorage};
+#[cfg(feature = "alloc")]
+pub use trie::TrieMap;
+pub use trie::{ArrayTrieMap, PrefixIter, Trie, TrieNode};
 
 pub trait Map<K, V>: Iterable {
     type Keys<'key>: Iterator<Item = &'key K>
@@ -15,6 +51,17 @@ pub trait Map<K, V>: Iterable {
     fn get(&self, k: &K) -> Option<&V>;
     fn keys<'c>(&'c self) -> Self::Keys<'c>;
     fn values<'c>(&'c self) -> Self::Values<'c>;
+
+    /// Returns the stored key together with its value.
+    ///
+    /// The default implementation scans `keys()` and `values()` in lockstep;
+    /// backends with direct lookup should override it.
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)>
+    where
+        K: PartialEq,
+    {
+        self.keys().zip(self.values()).find(|(key, _)| *key == k)
+    }
 }
 
 pub trait MapMut<K, V>: IterableMut {
@@ -27,6 +74,15 @@ pub trait MapMut<K, V>: IterableMut {
     fn insert(&mut self, key: K, value: V) -> Option<V>;
     fn remove(&mut self, key: &K) -> Option<V>;
     fn capacity(&mut self) -> usize;
+
+    /// Inserts a key-value pair, returning the pair back inside
+    /// [`Error::InsertFailed`] if the map cannot hold another entry.
+    ///
+    /// Unbounded maps never fail, so the default implementation simply
+    /// forwards to [`MapMut::insert`].
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Error<(K, V)>> {
+        Ok(self.insert(key, value))
+    }
 }
 
 #[cfg(feature = "std")]
@@ -37,7 +93,7 @@ mod std {
     };
 
     use super::Map;
-    use crate::{Iterable, IterableMut, MapMut};
+    use crate::{GetMut, Iterable, IterableMut, MapMut};
 
     mod inner_hashmap {
         use std::{
@@ -70,6 +126,14 @@ mod std {
             map.values_mut()
         }
 
+        #[inline(always)]
+        pub(crate) fn get_mut<'a, K: Hash + Eq, V>(
+            map: &'a mut HashMap<K, V>,
+            key: &K,
+        ) -> Option<&'a mut V> {
+            map.get_mut(key)
+        }
+
         #[inline(always)]
         pub(crate) fn len<K, V>(map: &HashMap<K, V>) -> usize {
             map.len()
@@ -85,6 +149,14 @@ mod std {
             map.get(key)
         }
 
+        #[inline(always)]
+        pub(crate) fn get_key_value<'a, K: Hash + Eq, V>(
+            map: &'a HashMap<K, V>,
+            key: &K,
+        ) -> Option<(&'a K, &'a V)> {
+            map.get_key_value(key)
+        }
+
         #[inline(always)]
         pub(crate) fn insert<K: Hash + Eq, V>(
             map: &mut HashMap<K, V>,
@@ -140,6 +212,11 @@ mod std {
             inner_hashmap::get(self, k)
         }
 
+        #[inline(always)]
+        fn get_key_value(&self, k: &K) -> Option<(&K, &V)> {
+            inner_hashmap::get_key_value(self, k)
+        }
+
         #[inline(always)]
         fn keys<'c>(&'c self) -> Self::Keys<'c> {
             inner_hashmap::keys(self)
@@ -194,6 +271,13 @@ mod std {
             inner_hashmap::values_mut(self)
         }
     }
+
+    impl<K: Hash + Eq, V> GetMut<K, V> for HashMap<K, V> {
+        #[inline(always)]
+        fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+            inner_hashmap::get_mut(self, k)
+        }
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -201,7 +285,7 @@ mod alloc {
     use alloc::collections::{btree_map, BTreeMap};
 
     use super::Map;
-    use crate::{Iterable, IterableMut, MapMut};
+    use crate::{GetMut, Iterable, IterableMut, MapMut};
 
     mod inner_btreemap {
         use alloc::collections::{btree_map, BTreeMap};
@@ -231,6 +315,14 @@ mod alloc {
             map.values_mut()
         }
 
+        #[inline(always)]
+        pub(crate) fn get_mut<'a, K: Ord, V>(
+            map: &'a mut BTreeMap<K, V>,
+            key: &K,
+        ) -> Option<&'a mut V> {
+            map.get_mut(key)
+        }
+
         #[inline(always)]
         pub(crate) fn len<K, V>(map: &BTreeMap<K, V>) -> usize {
             map.len()
@@ -241,6 +333,14 @@ mod alloc {
             map.get(key)
         }
 
+        #[inline(always)]
+        pub(crate) fn get_key_value<'a, K: Ord, V>(
+            map: &'a BTreeMap<K, V>,
+            key: &K,
+        ) -> Option<(&'a K, &'a V)> {
+            map.get_key_value(key)
+        }
+
         #[inline(always)]
         pub(crate) fn insert<K: Ord, V>(map: &mut BTreeMap<K, V>, key: K, value: V) -> Option<V> {
             map.insert(key, value)
@@ -292,6 +392,11 @@ mod alloc {
             inner_btreemap::get(self, k)
         }
 
+        #[inline(always)]
+        fn get_key_value(&self, k: &K) -> Option<(&K, &V)> {
+            inner_btreemap::get_key_value(self, k)
+        }
+
         #[inline(always)]
         fn keys<'c>(&'c self) -> Self::Keys<'c> {
             inner_btreemap::keys(self)
@@ -347,4 +452,11 @@ mod alloc {
             inner_btreemap::values_mut(self)
         }
     }
+
+    impl<K: Ord, V> GetMut<K, V> for BTreeMap<K, V> {
+        #[inline(always)]
+        fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+            inner_btreemap::get_mut(self, k)
+        }
+    }
 }
@@ -0,0 +1,392 @@
+use alloc::vec::Vec;
+use core::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+
+use super::{Map, MapMut};
+use crate::{GetMut, Iterable, IterableMut};
+
+const EMPTY: usize = usize::MAX;
+
+/// 64-bit FNV-1a hasher, for hashed collections in `no_std` builds, which
+/// have no randomly seeded hasher to default to.
+///
+/// FNV is fast on short keys but unkeyed, so anyone choosing the keys can
+/// force collisions. Prefer the `std` default wherever it is available.
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+pub type FnvBuildHasher = BuildHasherDefault<FnvHasher>;
+
+/// Entry of an [`OrderedHashMap`], with its key's cached hash.
+#[derive(Debug, Clone)]
+pub struct Bucket<K, V> {
+    hash: u64,
+    key: K,
+    value: V,
+}
+
+/// Hash map that remembers insertion order.
+///
+/// Entries are stored densely in a vector in the order they were inserted,
+/// and an open-addressed table of positions into that vector provides hashed
+/// lookup. Iteration walks the vector, so it is deterministic regardless of
+/// the hasher.
+///
+/// [`MapMut::remove`] preserves the order of the remaining entries, which
+/// costs O(n); use [`OrderedHashMap::swap_remove`] for an O(1) removal that
+/// moves the last entry into the gap.
+///
+/// With `std` the hasher defaults to [`RandomState`]. Without it there is no
+/// default, and one such as [`FnvBuildHasher`] has to be chosen explicitly.
+///
+/// [`RandomState`]: std::collections::hash_map::RandomState
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct OrderedHashMap<K, V, S = std::collections::hash_map::RandomState> {
+    entries: Vec<Bucket<K, V>>,
+    table: Vec<usize>,
+    hasher: S,
+}
+
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Clone)]
+pub struct OrderedHashMap<K, V, S> {
+    entries: Vec<Bucket<K, V>>,
+    table: Vec<usize>,
+    hasher: S,
+}
+
+#[cfg(feature = "std")]
+impl<K, V> OrderedHashMap<K, V> {
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+
+impl<K, V, S: Default> Default for OrderedHashMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> OrderedHashMap<K, V, S> {
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            entries: Vec::new(),
+            table: Vec::new(),
+            hasher,
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entry at position `index` in insertion order.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|b| (&b.key, &b.value))
+    }
+
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.entries.get_mut(index).map(|b| (&b.key, &mut b.value))
+    }
+
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.get_index(self.len().wrapping_sub(1))
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.table.iter_mut().for_each(|slot| *slot = EMPTY);
+    }
+
+    #[inline(always)]
+    fn mask(&self) -> usize {
+        self.table.len().wrapping_sub(1)
+    }
+
+    /// Table slot holding `index`, whose entry hashed to `hash`.
+    fn slot_of(&self, hash: u64, index: usize) -> usize {
+        let mask = self.mask();
+        let mut slot = hash as usize & mask;
+        while self.table[slot] != index {
+            slot = (slot + 1) & mask;
+        }
+        slot
+    }
+
+    /// Places `index` into the first free slot on its probe sequence.
+    fn place(&mut self, hash: u64, index: usize) {
+        let mask = self.mask();
+        let mut slot = hash as usize & mask;
+        while self.table[slot] != EMPTY {
+            slot = (slot + 1) & mask;
+        }
+        self.table[slot] = index;
+    }
+
+    /// Empties `slot`, shifting later entries of the same probe run back so
+    /// that lookups never need tombstones.
+    fn vacate(&mut self, mut slot: usize) {
+        let mask = self.mask();
+        self.table[slot] = EMPTY;
+        let mut next = (slot + 1) & mask;
+        while self.table[next] != EMPTY {
+            let ideal = self.entries[self.table[next]].hash as usize & mask;
+            if (next.wrapping_sub(ideal) & mask) >= (next.wrapping_sub(slot) & mask) {
+                self.table[slot] = self.table[next];
+                self.table[next] = EMPTY;
+                slot = next;
+            }
+            next = (next + 1) & mask;
+        }
+    }
+
+    /// Keeps the table at most three quarters full.
+    fn reserve_one(&mut self) {
+        if (self.entries.len() + 1) * 4 <= self.table.len() * 3 {
+            return;
+        }
+        let size = (self.table.len() * 2).max(8);
+        self.table.clear();
+        self.table.resize(size, EMPTY);
+        for index in 0..self.entries.len() {
+            self.place(self.entries[index].hash, index);
+        }
+    }
+
+    /// Removes the entry at `index`, moving the last entry into its place.
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        self.vacate(self.slot_of(hash, index));
+        let last = self.entries.len() - 1;
+        if index != last {
+            let slot = self.slot_of(self.entries[last].hash, last);
+            self.table[slot] = index;
+        }
+        let bucket = self.entries.swap_remove(index);
+        Some((bucket.key, bucket.value))
+    }
+
+    /// Removes the entry at `index`, shifting all later entries down by one
+    /// to keep their order.
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        self.vacate(self.slot_of(hash, index));
+        for slot in self.table.iter_mut() {
+            if *slot != EMPTY && *slot > index {
+                *slot -= 1;
+            }
+        }
+        let bucket = self.entries.remove(index);
+        Some((bucket.key, bucket.value))
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> OrderedHashMap<K, V, S> {
+    fn hash(&self, key: &K) -> u64 {
+        let mut hasher = self.hasher.build_hasher();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn find(&self, hash: u64, key: &K) -> Option<usize> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let mask = self.mask();
+        let mut slot = hash as usize & mask;
+        loop {
+            let index = self.table[slot];
+            if index == EMPTY {
+                return None;
+            }
+            let bucket = &self.entries[index];
+            if bucket.hash == hash && bucket.key == *key {
+                return Some(index);
+            }
+            slot = (slot + 1) & mask;
+        }
+    }
+
+    /// Position of `key` in insertion order.
+    pub fn get_index_of(&self, key: &K) -> Option<usize> {
+        self.find(self.hash(key), key)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Inserts a pair, returning its position and the previous value.
+    ///
+    /// A key that is already present keeps its original position.
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        let hash = self.hash(&key);
+        if let Some(index) = self.find(hash, &key) {
+            let old = core::mem::replace(&mut self.entries[index].value, value);
+            return (index, Some(old));
+        }
+        self.reserve_one();
+        let index = self.entries.len();
+        self.place(hash, index);
+        self.entries.push(Bucket { hash, key, value });
+        (index, None)
+    }
+
+    /// Removes `key` in O(1) by moving the last entry into its place.
+    pub fn swap_remove(&mut self, key: &K) -> Option<V> {
+        let index = self.get_index_of(key)?;
+        self.swap_remove_index(index).map(|(_, v)| v)
+    }
+
+    /// Removes `key` in O(n), keeping the order of the remaining entries.
+    pub fn shift_remove(&mut self, key: &K) -> Option<V> {
+        let index = self.get_index_of(key)?;
+        self.shift_remove_index(index).map(|(_, v)| v)
+    }
+}
+
+type Iter<'a, K, V> =
+    core::iter::Map<core::slice::Iter<'a, Bucket<K, V>>, fn(&'a Bucket<K, V>) -> (&'a K, &'a V)>;
+type IterMut<'a, K, V> = core::iter::Map<
+    core::slice::IterMut<'a, Bucket<K, V>>,
+    fn(&'a mut Bucket<K, V>) -> (&'a K, &'a mut V),
+>;
+type Keys<'a, K, V> =
+    core::iter::Map<core::slice::Iter<'a, Bucket<K, V>>, fn(&'a Bucket<K, V>) -> &'a K>;
+type Values<'a, K, V> =
+    core::iter::Map<core::slice::Iter<'a, Bucket<K, V>>, fn(&'a Bucket<K, V>) -> &'a V>;
+type ValuesMut<'a, K, V> =
+    core::iter::Map<core::slice::IterMut<'a, Bucket<K, V>>, fn(&'a mut Bucket<K, V>) -> &'a mut V>;
+
+impl<K, V, S> Iterable for OrderedHashMap<K, V, S> {
+    type Item<'collection>
+        = (&'collection K, &'collection V)
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection, K, V>
+    where
+        Self: 'collection;
+
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.entries.iter().map(|b| (&b.key, &b.value))
+    }
+}
+
+impl<K, V, S> IterableMut for OrderedHashMap<K, V, S> {
+    type ItemMut<'collection>
+        = (&'collection K, &'collection mut V)
+    where
+        Self: 'collection;
+
+    type IteratorMut<'collection>
+        = IterMut<'collection, K, V>
+    where
+        Self: 'collection;
+
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        self.entries.iter_mut().map(|b| (&b.key, &mut b.value))
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Map<K, V> for OrderedHashMap<K, V, S> {
+    type Keys<'key>
+        = Keys<'key, K, V>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = Values<'value, K, V>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn get(&self, k: &K) -> Option<&V> {
+        let index = self.get_index_of(k)?;
+        Some(&self.entries[index].value)
+    }
+
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        self.entries.iter().map(|b| &b.key)
+    }
+
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        self.entries.iter().map(|b| &b.value)
+    }
+
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)> {
+        self.get_index(self.get_index_of(k)?)
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> MapMut<K, V> for OrderedHashMap<K, V, S> {
+    type ValuesMut<'value>
+        = ValuesMut<'value, K, V>
+    where
+        V: 'value,
+        Self: 'value;
+
+    fn values_mut<'c>(&'c mut self) -> Self::ValuesMut<'c> {
+        self.entries.iter_mut().map(|b| &mut b.value)
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    /// Removes `key` while keeping the order of the remaining entries.
+    #[inline(always)]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.shift_remove(key)
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.entries.capacity()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> GetMut<K, V> for OrderedHashMap<K, V, S> {
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        let index = self.get_index_of(k)?;
+        Some(&mut self.entries[index].value)
+    }
+}
@@ -0,0 +1,506 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash, Hasher};
+
+use super::{Map, MapMut};
+use crate::{GetMut, Iterable, IterableMut};
+
+/// Hash bits consumed per trie level.
+const BITS: u32 = 5;
+const MASK: u64 = (1 << BITS) - 1;
+
+#[derive(Clone)]
+enum Node<K, V> {
+    /// Children for each hash fragment whose bit is set in `bitmap`.
+    Branch {
+        bitmap: u32,
+        children: Vec<Arc<Node<K, V>>>,
+    },
+    /// Entries whose keys all have the same full hash.
+    Leaf { hash: u64, entries: Vec<(K, V)> },
+}
+
+impl<K, V> Node<K, V> {
+    const EMPTY: Self = Node::Branch {
+        bitmap: 0,
+        children: Vec::new(),
+    };
+
+    /// Position in `children` of the child for `hash` at `shift`, if any.
+    #[inline(always)]
+    fn slot(bitmap: u32, hash: u64, shift: u32) -> (u32, usize) {
+        let bit = 1 << ((hash >> shift) & MASK);
+        (bit, (bitmap & (bit - 1)).count_ones() as usize)
+    }
+
+    fn get(&self, hash: u64, key: &K) -> Option<(&K, &V)>
+    where
+        K: Eq,
+    {
+        let mut node = self;
+        let mut shift = 0;
+        loop {
+            match node {
+                Node::Branch { bitmap, children } => {
+                    let (bit, index) = Self::slot(*bitmap, hash, shift);
+                    if bitmap & bit == 0 {
+                        return None;
+                    }
+                    node = &children[index];
+                    shift += BITS;
+                }
+                Node::Leaf { hash: h, entries } => {
+                    if *h != hash {
+                        return None;
+                    }
+                    return entries.iter().find(|(k, _)| k == key).map(|(k, v)| (k, v));
+                }
+            }
+        }
+    }
+}
+
+impl<K: Eq + Clone, V: Clone> Node<K, V> {
+    fn get_mut(&mut self, hash: u64, key: &K, shift: u32) -> Option<&mut V> {
+        match self {
+            Node::Branch { bitmap, children } => {
+                let (bit, index) = Self::slot(*bitmap, hash, shift);
+                if *bitmap & bit == 0 {
+                    return None;
+                }
+                Arc::make_mut(&mut children[index]).get_mut(hash, key, shift + BITS)
+            }
+            Node::Leaf { hash: h, entries } => {
+                if *h != hash {
+                    return None;
+                }
+                entries.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v)
+            }
+        }
+    }
+
+    /// Inserts into a branch at `shift`, returning the previous value.
+    fn insert(&mut self, hash: u64, key: K, value: V, shift: u32) -> Option<V> {
+        let Node::Branch { bitmap, children } = self else {
+            unreachable!("insertion starts at a branch");
+        };
+        let (bit, index) = Self::slot(*bitmap, hash, shift);
+        if *bitmap & bit == 0 {
+            *bitmap |= bit;
+            children.insert(
+                index,
+                Arc::new(Node::Leaf {
+                    hash,
+                    entries: Vec::from([(key, value)]),
+                }),
+            );
+            return None;
+        }
+        let child = Arc::make_mut(&mut children[index]);
+        match child {
+            Node::Branch { .. } => child.insert(hash, key, value, shift + BITS),
+            Node::Leaf { hash: h, entries } if *h == hash => {
+                match entries.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, old)) => Some(core::mem::replace(old, value)),
+                    None => {
+                        entries.push((key, value));
+                        None
+                    }
+                }
+            }
+            Node::Leaf { hash: h, .. } => {
+                // Two different hashes share a prefix here; push the existing
+                // leaf one level down and try again.
+                let (leaf_bit, _) = Self::slot(0, *h, shift + BITS);
+                let leaf = core::mem::replace(child, Node::EMPTY);
+                *child = Node::Branch {
+                    bitmap: leaf_bit,
+                    children: Vec::from([Arc::new(leaf)]),
+                };
+                child.insert(hash, key, value, shift + BITS)
+            }
+        }
+    }
+
+    /// Removes from a branch at `shift`, collapsing branches left holding a
+    /// single leaf so that the shape only depends on the keys present.
+    fn remove(&mut self, hash: u64, key: &K, shift: u32) -> Option<(K, V)> {
+        let Node::Branch { bitmap, children } = self else {
+            unreachable!("removal starts at a branch");
+        };
+        let (bit, index) = Self::slot(*bitmap, hash, shift);
+        if *bitmap & bit == 0 {
+            return None;
+        }
+        let child = Arc::make_mut(&mut children[index]);
+        let removed = match child {
+            Node::Branch { .. } => {
+                let removed = child.remove(hash, key, shift + BITS)?;
+                if let Node::Branch {
+                    children: inner, ..
+                } = child
+                {
+                    if inner.len() == 1 && matches!(*inner[0], Node::Leaf { .. }) {
+                        let leaf = inner.pop().expect("one child");
+                        *child = Arc::try_unwrap(leaf).unwrap_or_else(|shared| (*shared).clone());
+                    }
+                }
+                removed
+            }
+            Node::Leaf { hash: h, entries } => {
+                if *h != hash {
+                    return None;
+                }
+                let position = entries.iter().position(|(k, _)| k == key)?;
+                entries.swap_remove(position)
+            }
+        };
+        let empty = match &*children[index] {
+            Node::Leaf { entries, .. } => entries.is_empty(),
+            Node::Branch { children, .. } => children.is_empty(),
+        };
+        if empty {
+            *bitmap &= !bit;
+            children.remove(index);
+        }
+        Some(removed)
+    }
+}
+
+/// Persistent hash map sharing structure between copies.
+///
+/// Entries live in a hash array mapped trie behind [`Arc`]s, so
+/// [`Clone::clone`] is O(1) and a clone is an independent snapshot: changing
+/// one copy duplicates only the nodes on the path to the changed entry,
+/// leaving every other copy as it was. Lookups and updates take O(log n)
+/// steps of 32-way branching.
+///
+/// Iteration order depends on the hashes of the keys, not on insertion
+/// order. The hasher defaults the same way as [`OrderedHashMap`]'s.
+///
+/// [`OrderedHashMap`]: super::OrderedHashMap
+#[cfg(feature = "std")]
+pub struct PMap<K, V, S = std::collections::hash_map::RandomState> {
+    root: Arc<Node<K, V>>,
+    len: usize,
+    hasher: S,
+}
+
+#[cfg(not(feature = "std"))]
+pub struct PMap<K, V, S> {
+    root: Arc<Node<K, V>>,
+    len: usize,
+    hasher: S,
+}
+
+#[cfg(feature = "std")]
+impl<K, V> PMap<K, V> {
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+
+impl<K, V, S: Default> Default for PMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> PMap<K, V, S> {
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            root: Arc::new(Node::EMPTY),
+            len: 0,
+            hasher,
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether `self` and `other` are copies of the same snapshot, sharing
+    /// all of their structure.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.root, &other.root)
+    }
+
+    /// Empties this copy, leaving other snapshots untouched.
+    pub fn clear(&mut self) {
+        self.root = Arc::new(Node::EMPTY);
+        self.len = 0;
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            stack: Vec::from([core::slice::from_ref(&self.root).iter()]),
+            leaf: [].iter(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> PMap<K, V, S> {
+    fn hash(&self, key: &K) -> u64 {
+        let mut hasher = self.hasher.build_hasher();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    pub fn get_key_value(&self, key: &K) -> Option<(&K, &V)> {
+        self.root.get(self.hash(key), key)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_key_value(key).is_some()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher> PMap<K, V, S> {
+    /// Copies the path to the entry first if it is shared with another
+    /// snapshot.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let hash = self.hash(key);
+        // Avoid copying a shared path for a key that is not there.
+        self.root.get(hash, key)?;
+        Arc::make_mut(&mut self.root).get_mut(hash, key, 0)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hash(&key);
+        let old = Arc::make_mut(&mut self.root).insert(hash, key, value, 0);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let hash = self.hash(key);
+        self.root.get(hash, key)?;
+        let (_, value) = Arc::make_mut(&mut self.root).remove(hash, key, 0)?;
+        self.len -= 1;
+        Some(value)
+    }
+}
+
+impl<K, V, S: Clone> Clone for PMap<K, V, S> {
+    /// Makes a snapshot in O(1).
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+impl<K: Debug, V: Debug, S> Debug for PMap<K, V, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq, S: BuildHasher> PartialEq for PMap<K, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+            || (self.len == other.len && self.iter().all(|(k, v)| other.get(k) == Some(v)))
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher + Default> FromIterator<(K, V)>
+    for PMap<K, V, S>
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+/// Iterator over the entries of a [`PMap`].
+pub struct Iter<'a, K, V> {
+    stack: Vec<core::slice::Iter<'a, Arc<Node<K, V>>>>,
+    leaf: core::slice::Iter<'a, (K, V)>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((k, v)) = self.leaf.next() {
+                self.remaining -= 1;
+                return Some((k, v));
+            }
+            let level = self.stack.last_mut()?;
+            match level.next().map(|node| &**node) {
+                Some(Node::Leaf { entries, .. }) => self.leaf = entries.iter(),
+                Some(Node::Branch { children, .. }) => self.stack.push(children.iter()),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+/// Mutable iterator over the entries of a [`PMap`], copying shared nodes as
+/// it reaches them.
+pub struct IterMut<'a, K, V> {
+    stack: Vec<core::slice::IterMut<'a, Arc<Node<K, V>>>>,
+    leaf: core::slice::IterMut<'a, (K, V)>,
+}
+
+impl<'a, K: Clone, V: Clone> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((k, v)) = self.leaf.next() {
+                return Some((&*k, v));
+            }
+            let level = self.stack.last_mut()?;
+            match level.next().map(Arc::make_mut) {
+                Some(Node::Leaf { entries, .. }) => self.leaf = entries.iter_mut(),
+                Some(Node::Branch { children, .. }) => self.stack.push(children.iter_mut()),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+type Keys<'a, K, V> = core::iter::Map<Iter<'a, K, V>, fn((&'a K, &'a V)) -> &'a K>;
+type Values<'a, K, V> = core::iter::Map<Iter<'a, K, V>, fn((&'a K, &'a V)) -> &'a V>;
+type ValuesMut<'a, K, V> = core::iter::Map<IterMut<'a, K, V>, fn((&'a K, &'a mut V)) -> &'a mut V>;
+
+impl<K, V, S> Iterable for PMap<K, V, S> {
+    type Item<'collection>
+        = (&'collection K, &'collection V)
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection, K, V>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        PMap::iter(self)
+    }
+}
+
+impl<K: Clone, V: Clone, S> IterableMut for PMap<K, V, S> {
+    type ItemMut<'collection>
+        = (&'collection K, &'collection mut V)
+    where
+        Self: 'collection;
+
+    type IteratorMut<'collection>
+        = IterMut<'collection, K, V>
+    where
+        Self: 'collection;
+
+    /// Copies every node still shared with another snapshot.
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        IterMut {
+            stack: Vec::from([core::slice::from_mut(&mut self.root).iter_mut()]),
+            leaf: [].iter_mut(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Map<K, V> for PMap<K, V, S> {
+    type Keys<'key>
+        = Keys<'key, K, V>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = Values<'value, K, V>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn get(&self, k: &K) -> Option<&V> {
+        PMap::get(self, k)
+    }
+
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    #[inline(always)]
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)> {
+        PMap::get_key_value(self, k)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher> MapMut<K, V> for PMap<K, V, S> {
+    type ValuesMut<'value>
+        = ValuesMut<'value, K, V>
+    where
+        V: 'value,
+        Self: 'value;
+
+    fn values_mut<'c>(&'c mut self) -> Self::ValuesMut<'c> {
+        self.iter_mut().map(|(_, v)| v)
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        PMap::insert(self, key, value)
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        PMap::remove(self, key)
+    }
+
+    /// Nothing is allocated ahead of time, so this is the length.
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.len
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher> GetMut<K, V> for PMap<K, V, S> {
+    #[inline(always)]
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        PMap::get_mut(self, k)
+    }
+}
@@ -0,0 +1,411 @@
+use core::marker::PhantomData;
+
+use super::{Map, MapMut};
+use crate::{Error, GetMut, Iterable, IterableMut};
+
+const NIL: usize = usize::MAX;
+
+/// Generation of a vacant slot that is never reused. No key is handed out
+/// with it, so removing a value can always bump the generation without
+/// wrapping.
+const RETIRED: u32 = u32::MAX;
+
+/// Handle to a value in a [`SlotArena`].
+///
+/// A key stays valid until its value is removed, no matter what happens to
+/// other values; after that the slot's generation moves on and the key is
+/// stale, even if the slot is reused. A slot whose generation would wrap
+/// around is retired instead of reused, so a stale key never resolves again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    index: u32,
+    generation: u32,
+}
+
+impl Key {
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.index as usize
+    }
+
+    #[inline(always)]
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+/// Storage slot of a [`SlotArena`].
+#[derive(Debug, Clone)]
+pub struct Slot<T> {
+    key: Key,
+    value: Option<T>,
+    next_free: usize,
+}
+
+impl<T> Slot<T> {
+    fn vacant(index: usize) -> Self {
+        Self {
+            key: Key {
+                index: index as u32,
+                generation: 0,
+            },
+            value: None,
+            next_free: NIL,
+        }
+    }
+}
+
+/// Backing slots of a [`SlotArena`].
+pub trait SlotStorage<T> {
+    fn slots(&self) -> &[Slot<T>];
+    fn slots_mut(&mut self) -> &mut [Slot<T>];
+    /// Appends a vacant slot, returning `false` if the storage is fixed.
+    fn grow(&mut self) -> bool;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SlotStorage<T> for alloc::vec::Vec<Slot<T>> {
+    #[inline(always)]
+    fn slots(&self) -> &[Slot<T>] {
+        self
+    }
+
+    #[inline(always)]
+    fn slots_mut(&mut self) -> &mut [Slot<T>] {
+        self
+    }
+
+    fn grow(&mut self) -> bool {
+        if self.len() > u32::MAX as usize {
+            return false;
+        }
+        self.push(Slot::vacant(self.len()));
+        true
+    }
+}
+
+impl<T, const N: usize> SlotStorage<T> for [Slot<T>; N] {
+    #[inline(always)]
+    fn slots(&self) -> &[Slot<T>] {
+        self
+    }
+
+    #[inline(always)]
+    fn slots_mut(&mut self) -> &mut [Slot<T>] {
+        self
+    }
+
+    #[inline(always)]
+    fn grow(&mut self) -> bool {
+        false
+    }
+}
+
+/// Generational arena handing out stable [`Key`]s for its values.
+///
+/// Removing a value frees its slot for reuse without moving any other value,
+/// and bumps the slot's generation so that the old key no longer resolves.
+///
+/// Use [`SlotMap`] for a growable arena, or [`ArraySlotMap`] for one with a
+/// fixed number of slots that needs no allocator.
+///
+/// As a [`MapMut`], only keys handed out by the arena can be used:
+/// [`MapMut::insert`] replaces the value of a live key and panics for any
+/// other key, while [`MapMut::try_insert`] returns the pair back instead.
+///
+/// New values therefore go in through `insert_value` ([`SlotMap::insert_value`],
+/// [`ArraySlotMap::insert_value`]), which returns the new key, rather than an
+/// `insert(value)` that would clash with [`MapMut::insert`].
+#[derive(Debug, Clone)]
+pub struct SlotArena<T, S> {
+    slots: S,
+    free: usize,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+/// Growable [`SlotArena`].
+#[cfg(feature = "alloc")]
+pub type SlotMap<T> = SlotArena<T, alloc::vec::Vec<Slot<T>>>;
+
+/// [`SlotArena`] with `N` slots stored inline.
+pub type ArraySlotMap<T, const N: usize> = SlotArena<T, [Slot<T>; N]>;
+
+#[cfg(feature = "alloc")]
+impl<T> SlotMap<T> {
+    pub fn new() -> Self {
+        Self::from_slots(alloc::vec::Vec::new())
+    }
+
+    /// Stores `value`, returning its key.
+    pub fn insert_value(&mut self, value: T) -> Key {
+        match self.store(value) {
+            Ok(key) => key,
+            Err(_) => panic!("SlotMap cannot index more than u32::MAX slots"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Default for SlotMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ArraySlotMap<T, N> {
+    pub fn new() -> Self {
+        let mut index = 0;
+        Self::from_slots([(); N].map(|_| {
+            index += 1;
+            Slot::vacant(index - 1)
+        }))
+    }
+
+    /// Stores `value`, returning its key, or returning `value` back inside
+    /// [`Error::InsertFailed`] if every slot is taken.
+    pub fn insert_value(&mut self, value: T) -> Result<Key, Error<T>> {
+        self.store(value)
+    }
+}
+
+impl<T, const N: usize> Default for ArraySlotMap<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S: SlotStorage<T>> SlotArena<T, S> {
+    fn from_slots(mut slots: S) -> Self {
+        let mut free = NIL;
+        for (index, slot) in slots.slots_mut().iter_mut().enumerate().rev() {
+            slot.next_free = free;
+            free = index;
+        }
+        Self {
+            slots,
+            free,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.slot(key).is_some()
+    }
+
+    pub fn get(&self, key: Key) -> Option<&T> {
+        self.slot(key)?.value.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        let slot = self.slots.slots_mut().get_mut(key.index())?;
+        if slot.key != key {
+            return None;
+        }
+        slot.value.as_mut()
+    }
+
+    /// Removes the value for `key`, making `key` stale.
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        let free = self.free;
+        let slot = self.slots.slots_mut().get_mut(key.index())?;
+        if slot.key != key {
+            return None;
+        }
+        let value = slot.value.take()?;
+        slot.key.generation += 1;
+        self.len -= 1;
+        if slot.key.generation != RETIRED {
+            slot.next_free = free;
+            self.free = key.index();
+        }
+        Some(value)
+    }
+
+    /// Removes every value, making all keys stale.
+    pub fn clear(&mut self) {
+        let mut free = NIL;
+        for (index, slot) in self.slots.slots_mut().iter_mut().enumerate().rev() {
+            if slot.value.take().is_some() {
+                slot.key.generation += 1;
+            }
+            if slot.key.generation != RETIRED {
+                slot.next_free = free;
+                free = index;
+            }
+        }
+        self.free = free;
+        self.len = 0;
+    }
+
+    /// The occupied slot `key` refers to, if it is still live.
+    fn slot(&self, key: Key) -> Option<&Slot<T>> {
+        let slot = self.slots.slots().get(key.index())?;
+        (slot.key == key && slot.value.is_some()).then_some(slot)
+    }
+
+    pub(crate) fn store(&mut self, value: T) -> Result<Key, Error<T>> {
+        if self.free == NIL {
+            if !self.slots.grow() {
+                return Err(Error::InsertFailed(value));
+            }
+            self.free = self.slots.slots().len() - 1;
+            self.slots.slots_mut()[self.free].next_free = NIL;
+        }
+        let slot = &mut self.slots.slots_mut()[self.free];
+        self.free = slot.next_free;
+        slot.value = Some(value);
+        self.len += 1;
+        Ok(slot.key)
+    }
+}
+
+type Iter<'a, T> = core::iter::FilterMap<
+    core::slice::Iter<'a, Slot<T>>,
+    fn(&'a Slot<T>) -> Option<(&'a Key, &'a T)>,
+>;
+type IterMut<'a, T> = core::iter::FilterMap<
+    core::slice::IterMut<'a, Slot<T>>,
+    fn(&'a mut Slot<T>) -> Option<(&'a Key, &'a mut T)>,
+>;
+type Keys<'a, T> = core::iter::Map<Iter<'a, T>, fn((&'a Key, &'a T)) -> &'a Key>;
+type Values<'a, T> = core::iter::Map<Iter<'a, T>, fn((&'a Key, &'a T)) -> &'a T>;
+type ValuesMut<'a, T> = core::iter::Map<IterMut<'a, T>, fn((&'a Key, &'a mut T)) -> &'a mut T>;
+
+impl<T, S: SlotStorage<T>> Iterable for SlotArena<T, S> {
+    type Item<'collection>
+        = (&'collection Key, &'collection T)
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection, T>
+    where
+        Self: 'collection;
+
+    /// Iterates in slot order.
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.slots
+            .slots()
+            .iter()
+            .filter_map(|slot| Some((&slot.key, slot.value.as_ref()?)))
+    }
+}
+
+impl<T, S: SlotStorage<T>> IterableMut for SlotArena<T, S> {
+    type ItemMut<'collection>
+        = (&'collection Key, &'collection mut T)
+    where
+        Self: 'collection;
+
+    type IteratorMut<'collection>
+        = IterMut<'collection, T>
+    where
+        Self: 'collection;
+
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        self.slots
+            .slots_mut()
+            .iter_mut()
+            .filter_map(|slot| Some((&slot.key, slot.value.as_mut()?)))
+    }
+}
+
+impl<T, S: SlotStorage<T>> Map<Key, T> for SlotArena<T, S> {
+    type Keys<'key>
+        = Keys<'key, T>
+    where
+        Key: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = Values<'value, T>
+    where
+        T: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn get(&self, k: &Key) -> Option<&T> {
+        SlotArena::get(self, *k)
+    }
+
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    fn get_key_value(&self, k: &Key) -> Option<(&Key, &T)> {
+        let slot = self.slot(*k)?;
+        Some((&slot.key, slot.value.as_ref()?))
+    }
+}
+
+impl<T, S: SlotStorage<T>> MapMut<Key, T> for SlotArena<T, S> {
+    type ValuesMut<'value>
+        = ValuesMut<'value, T>
+    where
+        T: 'value,
+        Self: 'value;
+
+    fn values_mut<'c>(&'c mut self) -> Self::ValuesMut<'c> {
+        self.iter_mut().map(|(_, v)| v)
+    }
+
+    /// Replaces the value of a live key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is stale or was not handed out by this arena.
+    fn insert(&mut self, key: Key, value: T) -> Option<T> {
+        match self.try_insert(key, value) {
+            Ok(old) => old,
+            Err(_) => panic!("SlotArena key is stale"),
+        }
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, key: &Key) -> Option<T> {
+        SlotArena::remove(self, *key)
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.slots.slots().len()
+    }
+
+    /// Replaces the value of a live key, returning the pair back inside
+    /// [`Error::InsertFailed`] for any other key.
+    fn try_insert(&mut self, key: Key, value: T) -> Result<Option<T>, Error<(Key, T)>> {
+        match SlotArena::get_mut(self, key) {
+            Some(old) => Ok(Some(core::mem::replace(old, value))),
+            None => Err(Error::InsertFailed((key, value))),
+        }
+    }
+}
+
+impl<T, S: SlotStorage<T>> GetMut<Key, T> for SlotArena<T, S> {
+    #[inline(always)]
+    fn get_mut(&mut self, k: &Key) -> Option<&mut T> {
+        SlotArena::get_mut(self, *k)
+    }
+}
@@ -0,0 +1,511 @@
+use super::{ArraySlotMap, Key, Map, MapMut, Slot, SlotArena, SlotStorage};
+use crate::{Error, GetMut, Iterable, IterableMut};
+
+/// Node of a [`Trie`], holding one byte of the keys below it.
+///
+/// Children are kept as a singly linked list of siblings sorted by byte, so
+/// every node has the same size and the whole trie fits in a fixed pool.
+#[derive(Debug, Clone)]
+pub struct TrieNode<K, V> {
+    byte: u8,
+    parent: Option<Key>,
+    first_child: Option<Key>,
+    next_sibling: Option<Key>,
+    entry: Option<(K, V)>,
+}
+
+/// Prefix tree keyed by byte strings, such as `&str`, `String` or `Vec<u8>`.
+///
+/// Nodes live in a [`SlotArena`] pool. Use [`TrieMap`] for a trie that grows
+/// as needed, or [`ArrayTrieMap`] for one with a fixed pool of `N` nodes that
+/// needs no allocator; every byte of a key not shared with an earlier key
+/// takes one node, and inserting past the pool fails through
+/// [`MapMut::try_insert`].
+///
+/// Shared iteration visits keys in lexicographic byte order. Mutable
+/// iteration visits them in pool order instead.
+#[derive(Debug, Clone)]
+pub struct Trie<K, V, S> {
+    nodes: SlotArena<TrieNode<K, V>, S>,
+    root_child: Option<Key>,
+    root_entry: Option<(K, V)>,
+    len: usize,
+}
+
+/// Growable [`Trie`].
+#[cfg(feature = "alloc")]
+pub type TrieMap<K, V> = Trie<K, V, alloc::vec::Vec<Slot<TrieNode<K, V>>>>;
+
+/// [`Trie`] with a fixed pool of `N` nodes.
+pub type ArrayTrieMap<K, V, const N: usize> = Trie<K, V, [Slot<TrieNode<K, V>>; N]>;
+
+#[cfg(feature = "alloc")]
+impl<K, V> TrieMap<K, V> {
+    pub fn new() -> Self {
+        Self::from_pool(super::SlotMap::new())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V> Default for TrieMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const N: usize> ArrayTrieMap<K, V, N> {
+    pub fn new() -> Self {
+        Self::from_pool(ArraySlotMap::new())
+    }
+}
+
+impl<K, V, const N: usize> Default for ArrayTrieMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S: SlotStorage<TrieNode<K, V>>> Trie<K, V, S> {
+    fn from_pool(nodes: SlotArena<TrieNode<K, V>, S>) -> Self {
+        Self {
+            nodes,
+            root_child: None,
+            root_entry: None,
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of pool nodes in use.
+    #[inline(always)]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn get<Q: AsRef<[u8]> + ?Sized>(&self, key: &Q) -> Option<&V> {
+        self.entry(key.as_ref()).map(|(_, v)| v)
+    }
+
+    pub fn get_mut<Q: AsRef<[u8]> + ?Sized>(&mut self, key: &Q) -> Option<&mut V> {
+        let entry = match self.locate(key.as_ref())? {
+            None => &mut self.root_entry,
+            Some(node) => &mut self.node_mut(node).entry,
+        };
+        entry.as_mut().map(|(_, v)| v)
+    }
+
+    pub fn contains_key<Q: AsRef<[u8]> + ?Sized>(&self, key: &Q) -> bool {
+        self.entry(key.as_ref()).is_some()
+    }
+
+    /// Removes `key`, freeing any nodes only it was using.
+    pub fn remove<Q: AsRef<[u8]> + ?Sized>(&mut self, key: &Q) -> Option<V> {
+        let (_, value) = match self.locate(key.as_ref())? {
+            None => self.root_entry.take()?,
+            Some(node) => {
+                let entry = self.node_mut(node).entry.take()?;
+                self.prune(node);
+                entry
+            }
+        };
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// The entry with the longest key that is a prefix of `query`.
+    pub fn longest_prefix<Q: AsRef<[u8]> + ?Sized>(&self, query: &Q) -> Option<(&K, &V)> {
+        let mut best = self.root_entry.as_ref();
+        let mut node = None;
+        for byte in query.as_ref() {
+            let Some(child) = self.child(node, *byte) else {
+                break;
+            };
+            node = Some(child);
+            if let Some(entry) = &self.node(child).entry {
+                best = Some(entry);
+            }
+        }
+        best.map(|(k, v)| (k, v))
+    }
+
+    /// Iterates in lexicographic order over the entries whose keys start with
+    /// `prefix`.
+    pub fn iter_prefix<Q: AsRef<[u8]> + ?Sized>(&self, prefix: &Q) -> PrefixIter<'_, K, V, S> {
+        match self.locate(prefix.as_ref()) {
+            Some(None) => PrefixIter {
+                trie: self,
+                root: self.root_entry.as_ref(),
+                next: self.root_child,
+                top: None,
+            },
+            Some(Some(node)) => PrefixIter {
+                trie: self,
+                root: None,
+                next: Some(node),
+                top: Some(node),
+            },
+            None => PrefixIter {
+                trie: self,
+                root: None,
+                next: None,
+                top: None,
+            },
+        }
+    }
+
+    /// Iterates in lexicographic order over the keys that start with
+    /// `prefix`.
+    pub fn keys_with_prefix<Q: AsRef<[u8]> + ?Sized>(&self, prefix: &Q) -> Keys<'_, K, V, S> {
+        self.iter_prefix(prefix).map(|(k, _)| k)
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.root_child = None;
+        self.root_entry = None;
+        self.len = 0;
+    }
+
+    #[inline(always)]
+    fn node(&self, key: Key) -> &TrieNode<K, V> {
+        self.nodes.get(key).expect("trie links point at live nodes")
+    }
+
+    #[inline(always)]
+    fn node_mut(&mut self, key: Key) -> &mut TrieNode<K, V> {
+        self.nodes
+            .get_mut(key)
+            .expect("trie links point at live nodes")
+    }
+
+    #[inline(always)]
+    fn first_child(&self, parent: Option<Key>) -> Option<Key> {
+        match parent {
+            None => self.root_child,
+            Some(parent) => self.node(parent).first_child,
+        }
+    }
+
+    fn set_first_child(&mut self, parent: Option<Key>, child: Option<Key>) {
+        match parent {
+            None => self.root_child = child,
+            Some(parent) => self.node_mut(parent).first_child = child,
+        }
+    }
+
+    fn child(&self, parent: Option<Key>, byte: u8) -> Option<Key> {
+        let mut next = self.first_child(parent);
+        while let Some(key) = next {
+            let node = self.node(key);
+            if node.byte >= byte {
+                return (node.byte == byte).then_some(key);
+            }
+            next = node.next_sibling;
+        }
+        None
+    }
+
+    /// The node spelling out `bytes`, where `Some(None)` is the root.
+    fn locate(&self, bytes: &[u8]) -> Option<Option<Key>> {
+        let mut node = None;
+        for byte in bytes {
+            node = Some(self.child(node, *byte)?);
+        }
+        Some(node)
+    }
+
+    fn entry(&self, bytes: &[u8]) -> Option<&(K, V)> {
+        match self.locate(bytes)? {
+            None => self.root_entry.as_ref(),
+            Some(node) => self.node(node).entry.as_ref(),
+        }
+    }
+
+    /// Adds an empty child in byte order, failing if the pool is full.
+    fn add_child(&mut self, parent: Option<Key>, byte: u8) -> Option<Key> {
+        let mut prev = None;
+        let mut next = self.first_child(parent);
+        while let Some(key) = next {
+            let node = self.node(key);
+            if node.byte > byte {
+                break;
+            }
+            prev = Some(key);
+            next = node.next_sibling;
+        }
+        let child = self
+            .nodes
+            .store(TrieNode {
+                byte,
+                parent,
+                first_child: None,
+                next_sibling: next,
+                entry: None,
+            })
+            .ok()?;
+        match prev {
+            None => self.set_first_child(parent, Some(child)),
+            Some(prev) => self.node_mut(prev).next_sibling = Some(child),
+        }
+        Some(child)
+    }
+
+    /// Frees `node` and its ancestors for as long as they hold neither an
+    /// entry nor children.
+    fn prune(&mut self, mut node: Key) {
+        loop {
+            let current = self.node(node);
+            if current.entry.is_some() || current.first_child.is_some() {
+                return;
+            }
+            let (parent, next) = (current.parent, current.next_sibling);
+            if self.first_child(parent) == Some(node) {
+                self.set_first_child(parent, next);
+            } else {
+                let mut sibling = self.first_child(parent);
+                while let Some(key) = sibling {
+                    if self.node(key).next_sibling == Some(node) {
+                        self.node_mut(key).next_sibling = next;
+                        break;
+                    }
+                    sibling = self.node(key).next_sibling;
+                }
+            }
+            self.nodes.remove(node);
+            match parent {
+                Some(parent) => node = parent,
+                None => return,
+            }
+        }
+    }
+
+    /// The node after `node` in a preorder walk of the subtree under `top`,
+    /// where `None` stands for the whole trie.
+    fn preorder_next(&self, node: Key, top: Option<Key>) -> Option<Key> {
+        let mut current = self.node(node);
+        if current.first_child.is_some() {
+            return current.first_child;
+        }
+        let mut key = node;
+        loop {
+            if Some(key) == top {
+                return None;
+            }
+            if current.next_sibling.is_some() {
+                return current.next_sibling;
+            }
+            key = current.parent?;
+            current = self.node(key);
+        }
+    }
+}
+
+impl<K: AsRef<[u8]>, V, S: SlotStorage<TrieNode<K, V>>> Trie<K, V, S> {
+    fn insert_entry(&mut self, key: K, value: V) -> Result<Option<V>, Error<(K, V)>> {
+        let mut node = None;
+        for i in 0..key.as_ref().len() {
+            let byte = key.as_ref()[i];
+            node = match self.child(node, byte) {
+                Some(child) => Some(child),
+                None => match self.add_child(node, byte) {
+                    Some(child) => Some(child),
+                    None => {
+                        if let Some(node) = node {
+                            self.prune(node);
+                        }
+                        return Err(Error::InsertFailed((key, value)));
+                    }
+                },
+            };
+        }
+        let entry = match node {
+            None => &mut self.root_entry,
+            Some(node) => &mut self.node_mut(node).entry,
+        };
+        if let Some((_, old)) = entry {
+            return Ok(Some(core::mem::replace(old, value)));
+        }
+        *entry = Some((key, value));
+        self.len += 1;
+        Ok(None)
+    }
+}
+
+/// Iterator over the entries of a [`Trie`] in lexicographic key order.
+pub struct PrefixIter<'a, K, V, S> {
+    trie: &'a Trie<K, V, S>,
+    root: Option<&'a (K, V)>,
+    next: Option<Key>,
+    top: Option<Key>,
+}
+
+impl<'a, K, V, S: SlotStorage<TrieNode<K, V>>> Iterator for PrefixIter<'a, K, V, S> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((k, v)) = self.root.take() {
+            return Some((k, v));
+        }
+        while let Some(key) = self.next {
+            self.next = self.trie.preorder_next(key, self.top);
+            if let Some((k, v)) = &self.trie.node(key).entry {
+                return Some((k, v));
+            }
+        }
+        None
+    }
+}
+
+type IterMut<'a, K, V, S> = core::iter::Chain<
+    core::iter::Map<core::option::IterMut<'a, (K, V)>, fn(&'a mut (K, V)) -> (&'a K, &'a mut V)>,
+    core::iter::FilterMap<
+        <SlotArena<TrieNode<K, V>, S> as IterableMut>::IteratorMut<'a>,
+        fn((&'a Key, &'a mut TrieNode<K, V>)) -> Option<(&'a K, &'a mut V)>,
+    >,
+>;
+type Keys<'a, K, V, S> = core::iter::Map<PrefixIter<'a, K, V, S>, fn((&'a K, &'a V)) -> &'a K>;
+type Values<'a, K, V, S> = core::iter::Map<PrefixIter<'a, K, V, S>, fn((&'a K, &'a V)) -> &'a V>;
+type ValuesMut<'a, K, V, S> =
+    core::iter::Map<IterMut<'a, K, V, S>, fn((&'a K, &'a mut V)) -> &'a mut V>;
+
+impl<K, V, S: SlotStorage<TrieNode<K, V>>> Iterable for Trie<K, V, S> {
+    type Item<'collection>
+        = (&'collection K, &'collection V)
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = PrefixIter<'collection, K, V, S>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.iter_prefix(&[])
+    }
+}
+
+fn entry_mut<K, V>((k, v): &mut (K, V)) -> (&K, &mut V) {
+    (k, v)
+}
+
+fn node_entry_mut<'a, K, V>(
+    (_, node): (&Key, &'a mut TrieNode<K, V>),
+) -> Option<(&'a K, &'a mut V)> {
+    node.entry.as_mut().map(entry_mut)
+}
+
+impl<K, V, S: SlotStorage<TrieNode<K, V>>> IterableMut for Trie<K, V, S> {
+    type ItemMut<'collection>
+        = (&'collection K, &'collection mut V)
+    where
+        Self: 'collection;
+
+    type IteratorMut<'collection>
+        = IterMut<'collection, K, V, S>
+    where
+        Self: 'collection;
+
+    /// Iterates with the empty key first and the rest in pool order.
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        self.root_entry
+            .iter_mut()
+            .map(entry_mut as fn(_) -> _)
+            .chain(
+                self.nodes
+                    .iter_mut()
+                    .filter_map(node_entry_mut as fn(_) -> _),
+            )
+    }
+}
+
+impl<K: AsRef<[u8]>, V, S: SlotStorage<TrieNode<K, V>>> Map<K, V> for Trie<K, V, S> {
+    type Keys<'key>
+        = Keys<'key, K, V, S>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = Values<'value, K, V, S>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn get(&self, k: &K) -> Option<&V> {
+        Trie::get(self, k)
+    }
+
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)> {
+        self.entry(k.as_ref()).map(|(k, v)| (k, v))
+    }
+}
+
+impl<K: AsRef<[u8]>, V, S: SlotStorage<TrieNode<K, V>>> MapMut<K, V> for Trie<K, V, S> {
+    type ValuesMut<'value>
+        = ValuesMut<'value, K, V, S>
+    where
+        V: 'value,
+        Self: 'value;
+
+    fn values_mut<'c>(&'c mut self) -> Self::ValuesMut<'c> {
+        self.iter_mut().map(|(_, v)| v)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the node pool is full. Use [`MapMut::try_insert`] to handle
+    /// that case.
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.insert_entry(key, value) {
+            Ok(old) => old,
+            Err(_) => panic!("Trie node pool exhausted"),
+        }
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        Trie::remove(self, key)
+    }
+
+    /// Number of pool nodes available, counting those in use.
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        MapMut::capacity(&mut self.nodes)
+    }
+
+    #[inline(always)]
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Error<(K, V)>> {
+        self.insert_entry(key, value)
+    }
+}
+
+impl<K: AsRef<[u8]>, V, S: SlotStorage<TrieNode<K, V>>> GetMut<K, V> for Trie<K, V, S> {
+    #[inline(always)]
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        Trie::get_mut(self, k)
+    }
+}
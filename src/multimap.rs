@@ -0,0 +1,243 @@
+use crate::Error;
+
+pub trait MultiMap<K, V> {
+    type Values<'value>: Iterator<Item = &'value V>
+    where
+        V: 'value,
+        Self: 'value;
+
+    /// Iterates over every value stored under `key`, which is empty if the
+    /// key is absent.
+    fn get_all<'c>(&'c self, key: &K) -> Self::Values<'c>;
+    /// Number of distinct keys.
+    fn key_count(&self) -> usize;
+    /// Number of values across all keys.
+    fn total_len(&self) -> usize;
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.get_all(key).next().is_some()
+    }
+}
+
+pub trait MultiMapMut<K, V>: MultiMap<K, V> {
+    /// Adds `value` under `key`, returning whether it was stored.
+    ///
+    /// Set-backed multimaps return `Ok(false)` for a value already present.
+    fn insert(&mut self, key: K, value: V) -> Result<bool, Error<(K, V)>>;
+    /// Removes a single value equal to `value` stored under `key`.
+    fn remove_one(&mut self, key: &K, value: &V) -> bool
+    where
+        V: PartialEq;
+    /// Removes `key` and all of its values, returning how many were removed.
+    fn remove_all(&mut self, key: &K) -> usize;
+}
+
+#[cfg(feature = "std")]
+mod std {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+    use std::vec::Vec;
+
+    use super::{MultiMap, MultiMapMut};
+    use crate::Error;
+
+    impl<K: Hash + Eq, V> MultiMap<K, V> for HashMap<K, Vec<V>> {
+        type Values<'value>
+            = core::iter::Flatten<core::option::IntoIter<&'value Vec<V>>>
+        where
+            V: 'value,
+            Self: 'value;
+
+        #[inline(always)]
+        fn get_all<'c>(&'c self, key: &K) -> Self::Values<'c> {
+            self.get(key).into_iter().flatten()
+        }
+
+        #[inline(always)]
+        fn key_count(&self) -> usize {
+            self.len()
+        }
+
+        fn total_len(&self) -> usize {
+            self.values().map(Vec::len).sum()
+        }
+    }
+
+    impl<K: Hash + Eq, V> MultiMapMut<K, V> for HashMap<K, Vec<V>> {
+        fn insert(&mut self, key: K, value: V) -> Result<bool, Error<(K, V)>> {
+            self.entry(key).or_default().push(value);
+            Ok(true)
+        }
+
+        fn remove_one(&mut self, key: &K, value: &V) -> bool
+        where
+            V: PartialEq,
+        {
+            let Some(values) = self.get_mut(key) else {
+                return false;
+            };
+            let Some(index) = values.iter().position(|v| v == value) else {
+                return false;
+            };
+            values.remove(index);
+            if values.is_empty() {
+                self.remove(key);
+            }
+            true
+        }
+
+        fn remove_all(&mut self, key: &K) -> usize {
+            self.remove(key).map_or(0, |values| values.len())
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc {
+    use alloc::collections::{BTreeMap, BTreeSet};
+
+    use super::{MultiMap, MultiMapMut};
+    use crate::Error;
+
+    impl<K: Ord, V: Ord> MultiMap<K, V> for BTreeMap<K, BTreeSet<V>> {
+        type Values<'value>
+            = core::iter::Flatten<core::option::IntoIter<&'value BTreeSet<V>>>
+        where
+            V: 'value,
+            Self: 'value;
+
+        #[inline(always)]
+        fn get_all<'c>(&'c self, key: &K) -> Self::Values<'c> {
+            self.get(key).into_iter().flatten()
+        }
+
+        #[inline(always)]
+        fn key_count(&self) -> usize {
+            self.len()
+        }
+
+        fn total_len(&self) -> usize {
+            self.values().map(BTreeSet::len).sum()
+        }
+    }
+
+    impl<K: Ord, V: Ord> MultiMapMut<K, V> for BTreeMap<K, BTreeSet<V>> {
+        fn insert(&mut self, key: K, value: V) -> Result<bool, Error<(K, V)>> {
+            Ok(self.entry(key).or_default().insert(value))
+        }
+
+        fn remove_one(&mut self, key: &K, value: &V) -> bool
+        where
+            V: PartialEq,
+        {
+            let Some(values) = self.get_mut(key) else {
+                return false;
+            };
+            if !values.remove(value) {
+                return false;
+            }
+            if values.is_empty() {
+                self.remove(key);
+            }
+            true
+        }
+
+        fn remove_all(&mut self, key: &K) -> usize {
+            self.remove(key).map_or(0, |values| values.len())
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless {
+    use core::fmt::Debug;
+
+    use super::{MultiMap, MultiMapMut};
+    use crate::{Error, FlatMap, GetMut, Map, MapMut};
+
+    /// Fixed-capacity multimap holding up to `KEYS` keys with up to `VALUES`
+    /// values each, without allocating.
+    pub type FixedMultiMap<K, V, const KEYS: usize, const VALUES: usize> =
+        FlatMap<K, heapless::Vec<V, VALUES>, heapless::Vec<(K, heapless::Vec<V, VALUES>), KEYS>>;
+
+    impl<K, V, const KEYS: usize, const VALUES: usize> MultiMap<K, V>
+        for FixedMultiMap<K, V, KEYS, VALUES>
+    where
+        K: Ord + Debug,
+        V: PartialEq + Debug,
+    {
+        type Values<'value>
+            = core::iter::Flatten<core::option::IntoIter<&'value heapless::Vec<V, VALUES>>>
+        where
+            V: 'value,
+            Self: 'value;
+
+        #[inline(always)]
+        fn get_all<'c>(&'c self, key: &K) -> Self::Values<'c> {
+            self.get(key).into_iter().flatten()
+        }
+
+        #[inline(always)]
+        fn key_count(&self) -> usize {
+            Map::len(self)
+        }
+
+        fn total_len(&self) -> usize {
+            self.values().map(|values| values.len()).sum()
+        }
+    }
+
+    impl<K, V, const KEYS: usize, const VALUES: usize> MultiMapMut<K, V>
+        for FixedMultiMap<K, V, KEYS, VALUES>
+    where
+        K: Ord + Debug,
+        V: PartialEq + Debug,
+    {
+        fn insert(&mut self, key: K, value: V) -> Result<bool, Error<(K, V)>> {
+            if let Some(values) = self.get_mut(&key) {
+                return match values.push(value) {
+                    Ok(()) => Ok(true),
+                    Err(value) => Err(Error::InsertFailed((key, value))),
+                };
+            }
+
+            let mut values = heapless::Vec::new();
+            if let Err(value) = values.push(value) {
+                // Only reachable when VALUES is zero.
+                return Err(Error::InsertFailed((key, value)));
+            }
+            match self.try_insert(key, values) {
+                Ok(_) => Ok(true),
+                Err(Error::InsertFailed((key, mut values))) => match values.pop() {
+                    Some(value) => Err(Error::InsertFailed((key, value))),
+                    None => unreachable!(),
+                },
+                Err(Error::CapacityExceeded) => Err(Error::CapacityExceeded),
+            }
+        }
+
+        fn remove_one(&mut self, key: &K, value: &V) -> bool
+        where
+            V: PartialEq,
+        {
+            let Some(values) = self.get_mut(key) else {
+                return false;
+            };
+            let Some(index) = values.iter().position(|v| v == value) else {
+                return false;
+            };
+            values.remove(index);
+            if values.is_empty() {
+                MapMut::remove(self, key);
+            }
+            true
+        }
+
+        fn remove_all(&mut self, key: &K) -> usize {
+            MapMut::remove(self, key).map_or(0, |values| values.len())
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+pub use self::heapless::FixedMultiMap;
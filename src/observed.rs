@@ -0,0 +1,1008 @@
+use core::marker::PhantomData;
+
+use crate::{
+    Collection, CollectionMut, Error, GetMut, Iterable, IterableMut, List, ListMut, ListNew,
+    ListResizable, ListSortable, Map, MapMut, Set, SetMut,
+};
+
+/// One mutation recorded by an [`Observed`] collection.
+///
+/// `I` locates the change: the index for lists, the key for maps and the
+/// element itself for sets. `V` is the displaced value: the element for
+/// lists, the value for maps and `()` for sets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change<I, V> {
+    /// An element was inserted at `index`; read it from the collection.
+    Inserted { index: I },
+
+    /// The element at `index` was removed; `value` is what it held.
+    Removed { index: I, value: V },
+
+    /// The element at `index` was overwritten or handed out mutably;
+    /// `value` is what it held before.
+    Replaced { index: I, value: V },
+
+    /// Every element was removed.
+    Cleared,
+
+    /// Elements were reordered or handed out mutably in bulk; the length is
+    /// unchanged but any element may differ.
+    Sorted,
+}
+
+/// Receiver of the changes recorded by an [`Observed`] collection.
+pub trait Observer {
+    type Index;
+    type Value;
+
+    fn notify(&mut self, change: Change<Self::Index, Self::Value>);
+}
+
+/// Buffers every change until it is drained with
+/// [`Observed::take_changes`].
+#[cfg(feature = "alloc")]
+impl<I, V> Observer for alloc::vec::Vec<Change<I, V>> {
+    type Index = I;
+    type Value = V;
+
+    #[inline(always)]
+    fn notify(&mut self, change: Change<I, V>) {
+        self.push(change);
+    }
+}
+
+/// [`Observer`] that hands every change to a closure as it happens.
+pub struct Callback<F, I, V> {
+    f: F,
+    _marker: PhantomData<fn(Change<I, V>)>,
+}
+
+impl<F: FnMut(Change<I, V>), I, V> Callback<F, I, V> {
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F, I, V> core::fmt::Debug for Callback<F, I, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Callback").finish_non_exhaustive()
+    }
+}
+
+impl<F: FnMut(Change<I, V>), I, V> Observer for Callback<F, I, V> {
+    type Index = I;
+    type Value = V;
+
+    #[inline(always)]
+    fn notify(&mut self, change: Change<I, V>) {
+        (self.f)(change)
+    }
+}
+
+/// A run of list elements that changed: `removed` elements of the old list
+/// were replaced by the `inserted` elements now starting at `index`.
+///
+/// Applying a sequence of splices in order to a copy of the old list yields
+/// the current one, since each `index` already accounts for the splices
+/// before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Splice {
+    pub index: usize,
+    pub removed: usize,
+    pub inserted: usize,
+}
+
+/// [`Observer`] for lists that coalesces changes into the fewest
+/// non-adjacent [`Splice`]s, ordered by index.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct SpliceLog<T> {
+    len: usize,
+    splices: alloc::vec::Vec<Splice>,
+    _marker: PhantomData<fn(T)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SpliceLog<T> {
+    /// Creates an empty log for a list currently holding `len` elements.
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            splices: alloc::vec::Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn splices(&self) -> &[Splice] {
+        &self.splices
+    }
+
+    /// Drains the splices recorded so far, starting a new log from the
+    /// current contents.
+    pub fn take(&mut self) -> alloc::vec::Vec<Splice> {
+        core::mem::take(&mut self.splices)
+    }
+
+    /// Records that `removed` elements at `index` were replaced by `inserted`
+    /// ones, merging with every splice it overlaps or touches.
+    fn splice(&mut self, index: usize, removed: usize, inserted: usize) {
+        let first = self
+            .splices
+            .partition_point(|s| s.index + s.inserted < index);
+        let (mut lo, mut hi) = (index, index + removed);
+        let (mut merged_removed, mut merged_inserted) = (0, 0);
+        let mut last = first;
+        while let Some(s) = self.splices.get(last).filter(|s| s.index <= hi) {
+            lo = lo.min(s.index);
+            hi = hi.max(s.index + s.inserted);
+            merged_removed += s.removed;
+            merged_inserted += s.inserted;
+            last += 1;
+        }
+
+        for s in &mut self.splices[last..] {
+            s.index = s.index - removed + inserted;
+        }
+        let span = hi - lo;
+        let merged = Splice {
+            index: lo,
+            removed: span - merged_inserted + merged_removed,
+            inserted: span - removed + inserted,
+        };
+        let keep = merged.removed != 0 || merged.inserted != 0;
+        self.splices
+            .splice(first..last, core::iter::once(merged).filter(|_| keep));
+        self.len = self.len - removed + inserted;
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Observer for SpliceLog<T> {
+    type Index = usize;
+    type Value = T;
+
+    fn notify(&mut self, change: Change<usize, T>) {
+        match change {
+            Change::Inserted { index } => self.splice(index, 0, 1),
+            Change::Removed { index, .. } => self.splice(index, 1, 0),
+            Change::Replaced { index, .. } => self.splice(index, 1, 1),
+            Change::Cleared => self.splice(0, self.len, 0),
+            Change::Sorted => self.splice(0, self.len, self.len),
+        }
+    }
+}
+
+/// Wrapper that reports every mutation of the inner collection to an
+/// [`Observer`].
+///
+/// Mutable access to an element, through `get_mut` and friends, is reported
+/// up front as [`Change::Replaced`] whether or not the element is then
+/// changed, and bulk mutable access through `iter_mut` or `values_mut` as
+/// [`Change::Sorted`].
+///
+/// Collections created through [`ListNew::new`] or returned by
+/// [`ListMut::split_off`] have no observer until one is attached with
+/// [`Observed::observe`].
+#[derive(Debug, Clone)]
+pub struct Observed<C, O> {
+    inner: C,
+    observer: Option<O>,
+}
+
+impl<C, O: Observer> Observed<C, O> {
+    pub fn with_observer(inner: C, observer: O) -> Self {
+        Self {
+            inner,
+            observer: Some(observer),
+        }
+    }
+
+    /// Attaches `observer`, returning the one it replaces.
+    pub fn observe(&mut self, observer: O) -> Option<O> {
+        self.observer.replace(observer)
+    }
+
+    pub fn detach(&mut self) -> Option<O> {
+        self.observer.take()
+    }
+
+    pub fn observer(&self) -> Option<&O> {
+        self.observer.as_ref()
+    }
+
+    pub fn observer_mut(&mut self) -> Option<&mut O> {
+        self.observer.as_mut()
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// Builds a change from the inner collection only if someone is
+    /// listening, so unobserved collections never clone displaced values.
+    #[inline(always)]
+    fn emit<F>(&mut self, change: F)
+    where
+        F: FnOnce(&C) -> Change<O::Index, O::Value>,
+    {
+        if let Some(observer) = &mut self.observer {
+            observer.notify(change(&self.inner));
+        }
+    }
+}
+
+impl<C, F: FnMut(Change<I, V>), I, V> Observed<C, Callback<F, I, V>> {
+    pub fn with_callback(inner: C, f: F) -> Self {
+        Self::with_observer(inner, Callback::new(f))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C, I, V> Observed<C, alloc::vec::Vec<Change<I, V>>> {
+    pub fn buffered(inner: C) -> Self {
+        Self::with_observer(inner, alloc::vec::Vec::new())
+    }
+
+    pub fn changes(&self) -> &[Change<I, V>] {
+        self.observer.as_deref().unwrap_or(&[])
+    }
+
+    /// Drains the changes recorded since the last call.
+    pub fn take_changes(&mut self) -> alloc::vec::Vec<Change<I, V>> {
+        self.observer
+            .as_mut()
+            .map(core::mem::take)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> Observed<C, SpliceLog<T>> {
+    pub fn coalesced(inner: C) -> Self
+    where
+        C: Collection<T>,
+    {
+        let len = inner.len();
+        Self::with_observer(inner, SpliceLog::new(len))
+    }
+
+    pub fn splices(&self) -> &[Splice] {
+        self.observer.as_ref().map_or(&[], SpliceLog::splices)
+    }
+
+    /// Drains the splices recorded since the last call.
+    pub fn take_splices(&mut self) -> alloc::vec::Vec<Splice> {
+        self.observer
+            .as_mut()
+            .map(SpliceLog::take)
+            .unwrap_or_default()
+    }
+}
+
+impl<T, C: AsRef<[T]>, O> AsRef<[T]> for Observed<C, O> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        self.inner.as_ref()
+    }
+}
+
+impl<C: Iterable, O> Iterable for Observed<C, O> {
+    type Item<'collection>
+        = C::Item<'collection>
+    where
+        Self: 'collection;
+    type Iterator<'collection>
+        = C::Iterator<'collection>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.inner.iter()
+    }
+}
+
+impl<C: IterableMut, O: Observer> IterableMut for Observed<C, O> {
+    type ItemMut<'collection>
+        = C::ItemMut<'collection>
+    where
+        Self: 'collection;
+    type IteratorMut<'collection>
+        = C::IteratorMut<'collection>
+    where
+        Self: 'collection;
+
+    /// Reported as [`Change::Sorted`].
+    fn iter_mut<'c>(&'c mut self) -> Self::IteratorMut<'c> {
+        self.emit(|_| Change::Sorted);
+        self.inner.iter_mut()
+    }
+}
+
+impl<T, C: Collection<T>, O> Collection<T> for Observed<C, O> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.contains(other)
+    }
+}
+
+impl<T, C: CollectionMut<T>, O: Observer> CollectionMut<T> for Observed<C, O> {
+    fn clear(&mut self) {
+        if !self.inner.is_empty() {
+            self.emit(|_| Change::Cleared);
+        }
+        self.inner.clear()
+    }
+}
+
+impl<T, L: List<T>, O> List<T> for Observed<L, O> {
+    type Slice<'a>
+        = L::Slice<'a>
+    where
+        T: 'a,
+        Self: 'a;
+
+    #[inline(always)]
+    fn as_slice(&self) -> Self::Slice<'_> {
+        self.inner.as_slice()
+    }
+
+    #[inline(always)]
+    fn find_index(&self, other: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.inner.find_index(other)
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Option<&T> {
+        self.inner.first()
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Option<&T> {
+        self.inner.last()
+    }
+
+    #[inline(always)]
+    fn get(&self, index: usize) -> Option<&T> {
+        self.inner.get(index)
+    }
+
+    #[inline(always)]
+    fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.inner.binary_search(x)
+    }
+
+    #[inline(always)]
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        self.inner.binary_search_by(f)
+    }
+
+    #[inline(always)]
+    fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.inner.binary_search_by_key(b, f)
+    }
+
+    #[inline(always)]
+    fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.starts_with(needle)
+    }
+
+    #[inline(always)]
+    fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.ends_with(needle)
+    }
+}
+
+impl<T, L, O> Observed<L, O>
+where
+    T: Clone,
+    L: ListMut<T>,
+    O: Observer<Index = usize, Value = T>,
+{
+    /// Reports the elements from `from` to the end as removed, last first.
+    fn emit_removed_tail(&mut self, from: usize) {
+        for index in (from..self.inner.len()).rev() {
+            self.emit(|list| Change::Removed {
+                index,
+                value: list.get(index).unwrap().clone(),
+            });
+        }
+    }
+
+    fn emit_replaced_all(&mut self) {
+        for index in 0..self.inner.len() {
+            self.emit(|list| Change::Replaced {
+                index,
+                value: list.get(index).unwrap().clone(),
+            });
+        }
+    }
+}
+
+impl<T, L, O> ListNew<T> for Observed<L, O>
+where
+    T: Clone,
+    L: ListNew<T>,
+    O: Observer<Index = usize, Value = T>,
+{
+    /// Creates an empty list with no observer attached.
+    #[inline(always)]
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self {
+            inner: L::new(),
+            observer: None,
+        }
+    }
+}
+
+impl<T, L, O> ListMut<T> for Observed<L, O>
+where
+    T: Clone,
+    L: ListMut<T>,
+    O: Observer<Index = usize, Value = T>,
+{
+    type ElementMut<'a>
+        = L::ElementMut<'a>
+    where
+        T: 'a,
+        Self: 'a;
+
+    fn push(&mut self, item: T) -> Result<(), Error<T>> {
+        let index = self.inner.len();
+        self.inner.push(item)?;
+        self.emit(|_| Change::Inserted { index });
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let value = self.inner.pop()?;
+        let index = self.inner.len();
+        self.emit(|_| Change::Removed {
+            index,
+            value: value.clone(),
+        });
+        Some(value)
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn first_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        self.get_mut(0)
+    }
+
+    fn last_mut(&mut self) -> Option<Self::ElementMut<'_>> {
+        let index = self.inner.len().checked_sub(1)?;
+        self.get_mut(index)
+    }
+
+    /// Reported as [`Change::Replaced`] whether or not the element is then
+    /// changed.
+    fn get_mut(&mut self, index: usize) -> Option<Self::ElementMut<'_>> {
+        if index < self.inner.len() {
+            self.emit(|list| Change::Replaced {
+                index,
+                value: list.get(index).unwrap().clone(),
+            });
+        }
+        self.inner.get_mut(index)
+    }
+
+    fn set(&mut self, index: usize, value: T) -> Result<T, Error<T>> {
+        let old = self.inner.set(index, value)?;
+        self.emit(|_| Change::Replaced {
+            index,
+            value: old.clone(),
+        });
+        Ok(old)
+    }
+
+    fn insert(&mut self, index: usize, element: T) -> Result<(), Error<T>> {
+        self.inner.insert(index, element)?;
+        self.emit(|_| Change::Inserted { index });
+        Ok(())
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        let value = self.inner.remove(index);
+        self.emit(|_| Change::Removed {
+            index,
+            value: value.clone(),
+        });
+        value
+    }
+
+    /// Reported as the removal of the last element followed by the
+    /// replacement of the one at `index`, unless `index` was the last.
+    fn swap_remove(&mut self, index: usize) -> T {
+        let value = self.inner.swap_remove(index);
+        let last = self.inner.len();
+        if index == last {
+            self.emit(|_| Change::Removed {
+                index,
+                value: value.clone(),
+            });
+        } else {
+            self.emit(|list| Change::Removed {
+                index: last,
+                value: list.get(index).unwrap().clone(),
+            });
+            self.emit(|_| Change::Replaced {
+                index,
+                value: value.clone(),
+            });
+        }
+        value
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.inner.swap(a, b);
+        if a != b {
+            self.emit(|list| Change::Replaced {
+                index: a,
+                value: list.get(b).unwrap().clone(),
+            });
+            self.emit(|list| Change::Replaced {
+                index: b,
+                value: list.get(a).unwrap().clone(),
+            });
+        }
+    }
+
+    fn reverse(&mut self) {
+        self.inner.reverse();
+        if self.inner.len() > 1 {
+            self.emit(|_| Change::Sorted);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.emit_removed_tail(len);
+        self.inner.truncate(len)
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|x| f(x))
+    }
+
+    /// Relies on the backend visiting each element exactly once, in order,
+    /// as `Vec::retain_mut` does.
+    fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let observer = &mut self.observer;
+        let mut index = 0;
+        self.inner.retain_mut(|x| {
+            let keep = f(x);
+            if keep {
+                index += 1;
+            } else if let Some(observer) = observer {
+                observer.notify(Change::Removed {
+                    index,
+                    value: x.clone(),
+                });
+            }
+            keep
+        })
+    }
+
+    #[inline(always)]
+    fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Relies on the backend calling `same_bucket` once for each element
+    /// after the first, in order, as `Vec::dedup_by` does.
+    fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let observer = &mut self.observer;
+        let mut index = 1;
+        self.inner.dedup_by(|a, b| {
+            let same = same_bucket(a, b);
+            if !same {
+                index += 1;
+            } else if let Some(observer) = observer {
+                observer.notify(Change::Removed {
+                    index,
+                    value: a.clone(),
+                });
+            }
+            same
+        })
+    }
+
+    #[inline(always)]
+    fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.emit_replaced_all();
+        self.inner.fill(value)
+    }
+
+    fn fill_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.emit_replaced_all();
+        self.inner.fill_with(f)
+    }
+
+    /// Reported as insertions into `self` and as [`Change::Cleared`] to the
+    /// observer of `other`.
+    fn append(&mut self, other: &mut Self) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        let start = self.inner.len();
+        let moved = other.inner.len();
+        self.inner.append(&mut other.inner)?;
+        if moved > 0 {
+            for index in start..start + moved {
+                self.emit(|_| Change::Inserted { index });
+            }
+            other.emit(|_| Change::Cleared);
+        }
+        Ok(())
+    }
+
+    /// The returned tail has no observer attached.
+    fn split_off(&mut self, at: usize) -> Self
+    where
+        T: Clone,
+    {
+        self.emit_removed_tail(at);
+        Self {
+            inner: self.inner.split_off(at),
+            observer: None,
+        }
+    }
+}
+
+impl<T, L, O> ListResizable<T> for Observed<L, O>
+where
+    T: Clone,
+    L: ListResizable<T>,
+    O: Observer<Index = usize, Value = T>,
+{
+    fn resize(&mut self, new_len: usize, value: T) -> Result<(), Error<T>>
+    where
+        T: Clone,
+    {
+        let len = self.inner.len();
+        self.emit_removed_tail(new_len);
+        self.inner.resize(new_len, value)?;
+        for index in len..new_len {
+            self.emit(|_| Change::Inserted { index });
+        }
+        Ok(())
+    }
+
+    fn resize_with<F>(&mut self, new_len: usize, f: F) -> Result<(), Error<T>>
+    where
+        F: FnMut() -> T,
+    {
+        let len = self.inner.len();
+        self.emit_removed_tail(new_len);
+        self.inner.resize_with(new_len, f)?;
+        for index in len..new_len {
+            self.emit(|_| Change::Inserted { index });
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    #[inline(always)]
+    fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+}
+
+impl<L, O: Observer> Observed<L, O> {
+    fn emit_sorted<T>(&mut self)
+    where
+        L: Collection<T>,
+    {
+        if self.inner.len() > 1 {
+            self.emit(|_| Change::Sorted);
+        }
+    }
+}
+
+impl<T, L, O> ListSortable<T> for Observed<L, O>
+where
+    T: Clone,
+    L: ListSortable<T>,
+    O: Observer<Index = usize, Value = T>,
+{
+    fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.inner.sort();
+        self.emit_sorted::<T>()
+    }
+
+    fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        self.inner.sort_by(compare);
+        self.emit_sorted::<T>()
+    }
+
+    fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.inner.sort_by_key(f);
+        self.emit_sorted::<T>()
+    }
+
+    fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.inner.sort_unstable();
+        self.emit_sorted::<T>()
+    }
+
+    fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        self.inner.sort_unstable_by(compare);
+        self.emit_sorted::<T>()
+    }
+
+    fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.inner.sort_unstable_by_key(f);
+        self.emit_sorted::<T>()
+    }
+}
+
+impl<K, V, M: Map<K, V>, O> Map<K, V> for Observed<M, O> {
+    type Keys<'key>
+        = M::Keys<'key>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = M::Values<'value>
+    where
+        V: 'value,
+        Self: 'value;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    fn get(&self, k: &K) -> Option<&V> {
+        self.inner.get(k)
+    }
+
+    #[inline(always)]
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)>
+    where
+        K: PartialEq,
+    {
+        self.inner.get_key_value(k)
+    }
+
+    #[inline(always)]
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        self.inner.keys()
+    }
+
+    #[inline(always)]
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        self.inner.values()
+    }
+}
+
+impl<K, V, M, O> MapMut<K, V> for Observed<M, O>
+where
+    K: Clone,
+    V: Clone,
+    M: Map<K, V> + MapMut<K, V>,
+    O: Observer<Index = K, Value = V>,
+{
+    type ValuesMut<'value>
+        = M::ValuesMut<'value>
+    where
+        V: 'value,
+        Self: 'value;
+
+    /// Reported as [`Change::Sorted`].
+    fn values_mut<'c>(&'c mut self) -> Self::ValuesMut<'c> {
+        if Map::len(&self.inner) > 0 {
+            self.emit(|_| Change::Sorted);
+        }
+        self.inner.values_mut()
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let index = self.observer.as_ref().map(|_| key.clone());
+        let old = self.inner.insert(key, value);
+        if let Some(index) = index {
+            self.emit(|_| match &old {
+                Some(old) => Change::Replaced {
+                    index,
+                    value: old.clone(),
+                },
+                None => Change::Inserted { index },
+            });
+        }
+        old
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let value = self.inner.remove(key)?;
+        self.emit(|_| Change::Removed {
+            index: key.clone(),
+            value: value.clone(),
+        });
+        Some(value)
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Error<(K, V)>> {
+        let index = self.observer.as_ref().map(|_| key.clone());
+        let old = self.inner.try_insert(key, value)?;
+        if let Some(index) = index {
+            self.emit(|_| match &old {
+                Some(old) => Change::Replaced {
+                    index,
+                    value: old.clone(),
+                },
+                None => Change::Inserted { index },
+            });
+        }
+        Ok(old)
+    }
+}
+
+impl<K, V, M, O> GetMut<K, V> for Observed<M, O>
+where
+    K: Clone,
+    V: Clone,
+    M: Map<K, V> + GetMut<K, V>,
+    O: Observer<Index = K, Value = V>,
+{
+    /// Reported as [`Change::Replaced`] whether or not the value is then
+    /// changed.
+    fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        if self.inner.get(k).is_some() {
+            self.emit(|map| Change::Replaced {
+                index: k.clone(),
+                value: map.get(k).unwrap().clone(),
+            });
+        }
+        self.inner.get_mut(k)
+    }
+}
+
+impl<T, S: Set<T>, O> Set<T> for Observed<S, O> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        Set::len(&self.inner)
+    }
+
+    #[inline(always)]
+    fn get<'a>(&'a self, value: &'a T) -> Option<&'a T> {
+        self.inner.get(value)
+    }
+}
+
+impl<T, S, O> SetMut<T> for Observed<S, O>
+where
+    T: Clone,
+    S: SetMut<T>,
+    O: Observer<Index = T, Value = ()>,
+{
+    fn insert(&mut self, value: T) -> bool {
+        let index = self.observer.as_ref().map(|_| value.clone());
+        let inserted = self.inner.insert(value);
+        if let (true, Some(index)) = (inserted, index) {
+            self.emit(|_| Change::Inserted { index });
+        }
+        inserted
+    }
+
+    fn remove(&mut self, value: &T) -> bool {
+        let removed = self.inner.remove(value);
+        if removed {
+            self.emit(|_| Change::Removed {
+                index: value.clone(),
+                value: (),
+            });
+        }
+        removed
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn try_insert(&mut self, value: T) -> Result<bool, Error<T>> {
+        let index = self.observer.as_ref().map(|_| value.clone());
+        let inserted = self.inner.try_insert(value)?;
+        if let (true, Some(index)) = (inserted, index) {
+            self.emit(|_| Change::Inserted { index });
+        }
+        Ok(inserted)
+    }
+}
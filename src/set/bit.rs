@@ -0,0 +1,302 @@
+use super::{Set, SetMut};
+use crate::{Collection, CollectionMut, Error, Iterable};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Backing words of a bit collection, least significant bit first.
+pub trait BitStorage {
+    /// Storage holding no set bits, as small as the type allows.
+    fn empty() -> Self
+    where
+        Self: Sized;
+    fn words(&self) -> &[u64];
+    fn words_mut(&mut self) -> &mut [u64];
+    /// Makes room for at least `words` words, returning `false` if the
+    /// storage cannot grow that far.
+    fn grow(&mut self, words: usize) -> bool;
+}
+
+#[cfg(feature = "alloc")]
+impl BitStorage for alloc::vec::Vec<u64> {
+    #[inline(always)]
+    fn empty() -> Self {
+        alloc::vec::Vec::new()
+    }
+
+    #[inline(always)]
+    fn words(&self) -> &[u64] {
+        self
+    }
+
+    #[inline(always)]
+    fn words_mut(&mut self) -> &mut [u64] {
+        self
+    }
+
+    fn grow(&mut self, words: usize) -> bool {
+        if words > self.len() {
+            self.resize(words, 0);
+        }
+        true
+    }
+}
+
+impl<const WORDS: usize> BitStorage for [u64; WORDS] {
+    #[inline(always)]
+    fn empty() -> Self {
+        [0; WORDS]
+    }
+
+    #[inline(always)]
+    fn words(&self) -> &[u64] {
+        self
+    }
+
+    #[inline(always)]
+    fn words_mut(&mut self) -> &mut [u64] {
+        self
+    }
+
+    #[inline(always)]
+    fn grow(&mut self, words: usize) -> bool {
+        words <= WORDS
+    }
+}
+
+/// Set of small integers stored as one bit each.
+///
+/// Use [`BitSet`] for a set that grows to fit, or [`FixedBitSet`] for one
+/// holding values below `64 * WORDS` without an allocator. Inserting a value
+/// a fixed set cannot hold panics through [`SetMut::insert`] and is
+/// reported by [`SetMut::try_insert`].
+#[derive(Debug, Clone, Default)]
+pub struct WordSet<S> {
+    words: S,
+}
+
+/// Growable [`WordSet`].
+#[cfg(feature = "alloc")]
+pub type BitSet = WordSet<alloc::vec::Vec<u64>>;
+
+/// [`WordSet`] holding values below `64 * WORDS`.
+pub type FixedBitSet<const WORDS: usize> = WordSet<[u64; WORDS]>;
+
+#[cfg(feature = "alloc")]
+impl BitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a set with room for values below `bits` before it grows.
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: alloc::vec![0; bits / WORD_BITS + usize::from(bits % WORD_BITS != 0)],
+        }
+    }
+}
+
+impl<const WORDS: usize> FixedBitSet<WORDS> {
+    pub const fn new() -> Self {
+        Self { words: [0; WORDS] }
+    }
+}
+
+impl<S: BitStorage> WordSet<S> {
+    /// One past the largest value the set can hold without growing.
+    #[inline(always)]
+    pub fn bit_capacity(&self) -> usize {
+        self.words.words().len() * WORD_BITS
+    }
+
+    #[inline(always)]
+    pub fn contains(&self, value: usize) -> bool {
+        self.words
+            .words()
+            .get(value / WORD_BITS)
+            .map_or(false, |word| word & (1 << (value % WORD_BITS)) != 0)
+    }
+
+    /// Adds `value`, returning whether it was newly inserted, or returning it
+    /// back inside [`Error::InsertFailed`] if the storage cannot reach it.
+    pub fn insert(&mut self, value: usize) -> Result<bool, Error<usize>> {
+        if !self.words.grow(value / WORD_BITS + 1) {
+            return Err(Error::InsertFailed(value));
+        }
+        let word = &mut self.words.words_mut()[value / WORD_BITS];
+        let mask = 1 << (value % WORD_BITS);
+        let inserted = *word & mask == 0;
+        *word |= mask;
+        Ok(inserted)
+    }
+
+    /// Removes `value`, returning whether it was present.
+    pub fn remove(&mut self, value: usize) -> bool {
+        let Some(word) = self.words.words_mut().get_mut(value / WORD_BITS) else {
+            return false;
+        };
+        let mask = 1 << (value % WORD_BITS);
+        let removed = *word & mask != 0;
+        *word &= !mask;
+        removed
+    }
+
+    /// Number of values in the set.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .words()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Adds every value of `other`, a word at a time.
+    ///
+    /// Fails with [`Error::CapacityExceeded`], leaving `self` unchanged, if
+    /// `other` holds values `self` cannot.
+    pub fn union_with<O: BitStorage>(&mut self, other: &WordSet<O>) -> Result<(), Error<usize>> {
+        let other = other.words.words();
+        let used = other
+            .iter()
+            .rposition(|word| *word != 0)
+            .map_or(0, |i| i + 1);
+        if !self.words.grow(used) {
+            return Err(Error::CapacityExceeded);
+        }
+        for (word, other) in self.words.words_mut().iter_mut().zip(&other[..used]) {
+            *word |= other;
+        }
+        Ok(())
+    }
+
+    /// Keeps only the values also in `other`, a word at a time.
+    pub fn intersect_with<O: BitStorage>(&mut self, other: &WordSet<O>) {
+        let other = other.words.words();
+        for (i, word) in self.words.words_mut().iter_mut().enumerate() {
+            *word &= other.get(i).copied().unwrap_or(0);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.words.words_mut().fill(0);
+    }
+
+    /// The raw words, least significant bit first.
+    #[inline(always)]
+    pub fn as_words(&self) -> &[u64] {
+        self.words.words()
+    }
+}
+
+/// Sets are equal when they hold the same values, however many words each
+/// has allocated.
+impl<S: BitStorage, O: BitStorage> PartialEq<WordSet<O>> for WordSet<S> {
+    fn eq(&self, other: &WordSet<O>) -> bool {
+        let (a, b) = (self.as_words(), other.as_words());
+        let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+        long[..short.len()] == *short && long[short.len()..].iter().all(|word| *word == 0)
+    }
+}
+
+impl<S: BitStorage> Eq for WordSet<S> {}
+
+/// Iterator over the values of a [`WordSet`] in ascending order.
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    words: &'a [u64],
+    base: usize,
+    current: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            let (&word, rest) = self.words.split_first()?;
+            self.words = rest;
+            self.current = word;
+            self.base += WORD_BITS;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit.
+        self.current &= self.current - 1;
+        Some(self.base - WORD_BITS + bit)
+    }
+}
+
+impl<S: BitStorage> Iterable for WordSet<S> {
+    type Item<'collection>
+        = usize
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection>
+    where
+        Self: 'collection;
+
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        Iter {
+            words: self.words.words(),
+            base: 0,
+            current: 0,
+        }
+    }
+}
+
+impl<S: BitStorage> Collection<usize> for WordSet<S> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.count_ones()
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &usize) -> bool {
+        WordSet::contains(self, *other)
+    }
+}
+
+impl<S: BitStorage> CollectionMut<usize> for WordSet<S> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        WordSet::clear(self)
+    }
+}
+
+impl<S: BitStorage> Set<usize> for WordSet<S> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.count_ones()
+    }
+
+    fn get<'a>(&'a self, value: &'a usize) -> Option<&'a usize> {
+        WordSet::contains(self, *value).then_some(value)
+    }
+}
+
+impl<S: BitStorage> SetMut<usize> for WordSet<S> {
+    /// # Panics
+    ///
+    /// Panics if `value` is beyond what a fixed-size set can hold.
+    fn insert(&mut self, value: usize) -> bool {
+        match WordSet::insert(self, value) {
+            Ok(inserted) => inserted,
+            Err(_) => panic!("bit set cannot hold {value}"),
+        }
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, value: &usize) -> bool {
+        WordSet::remove(self, *value)
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        self.bit_capacity()
+    }
+
+    #[inline(always)]
+    fn try_insert(&mut self, value: usize) -> Result<bool, Error<usize>> {
+        WordSet::insert(self, value)
+    }
+}
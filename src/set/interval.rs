@@ -0,0 +1,160 @@
+use core::ops::Range;
+
+use super::Set;
+use crate::map::{IntervalStorage, Overlapping, RangeMap};
+use crate::{Collection, CollectionMut, Error, Iterable};
+
+/// Set of points stored as disjoint half-open ranges, built on
+/// [`RangeMap`].
+///
+/// Inserted ranges are joined with any range they overlap or touch, so the
+/// set always holds the fewest ranges covering its points. [`Set::len`] and
+/// [`Collection::len`] count those ranges, not points.
+///
+/// Use [`IntervalSet`] for a set built on `BTreeMap`, or
+/// [`FlatIntervalSet`] for one holding at most `N` ranges without an
+/// allocator.
+#[derive(Debug, Clone)]
+pub struct RangeSet<K, S> {
+    map: RangeMap<K, (), S>,
+}
+
+/// [`RangeSet`] stored in a `BTreeMap`.
+#[cfg(feature = "alloc")]
+pub type IntervalSet<K> = RangeSet<K, alloc::collections::BTreeMap<K, (K, ())>>;
+
+/// [`RangeSet`] holding at most `N` ranges without an allocator.
+#[cfg(feature = "heapless")]
+pub type FlatIntervalSet<K, const N: usize> = RangeSet<K, heapless::Vec<(K, K, ()), N>>;
+
+impl<K, S: IntervalStorage<K, ()>> RangeSet<K, S> {
+    pub fn new() -> Self {
+        Self {
+            map: RangeMap::new(),
+        }
+    }
+
+    /// Number of stored ranges.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<K, S: IntervalStorage<K, ()>> Default for RangeSet<K, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Clone, S: IntervalStorage<K, ()>> RangeSet<K, S> {
+    #[inline(always)]
+    pub fn contains_point(&self, point: &K) -> bool {
+        self.map.contains_point(point)
+    }
+
+    /// The range containing `point`.
+    pub fn get_interval(&self, point: &K) -> Option<Range<&K>> {
+        self.map.get_interval(point).map(|(range, _)| range)
+    }
+
+    /// Adds every point of `range`.
+    ///
+    /// Fails with [`Error::InsertFailed`], leaving the set unchanged, if the
+    /// result would need more ranges than the storage can hold.
+    pub fn insert_range(&mut self, range: Range<K>) -> Result<(), Error<Range<K>>> {
+        self.map
+            .insert_range(range, ())
+            .map_err(|error| match error {
+                Error::InsertFailed((range, ())) => Error::InsertFailed(range),
+                Error::CapacityExceeded => Error::CapacityExceeded,
+            })
+    }
+
+    /// Removes every point of `range`, splitting the range around it if
+    /// needed.
+    ///
+    /// Fails with [`Error::CapacityExceeded`], leaving the set unchanged, if
+    /// the split would need more room than the storage has.
+    #[inline(always)]
+    pub fn remove_range(&mut self, range: Range<K>) -> Result<(), Error<Range<K>>> {
+        self.map.remove_range(range)
+    }
+
+    /// Iterates in order over the ranges sharing at least one point with
+    /// `range`.
+    pub fn overlapping(&self, range: &Range<K>) -> OverlappingRanges<'_, K, S> {
+        self.map
+            .overlapping(range)
+            .map(|(start, end, _)| start..end)
+    }
+
+    /// Iterates in order over the parts of `range` not in the set.
+    #[inline(always)]
+    pub fn gaps(&self, range: &Range<K>) -> crate::map::Gaps<'_, K, (), S> {
+        self.map.gaps(range)
+    }
+}
+
+type OverlappingRanges<'a, K, S> =
+    core::iter::Map<Overlapping<'a, K, (), S>, fn((&'a K, &'a K, &'a ())) -> Range<&'a K>>;
+type Iter<'a, K, S> = core::iter::Map<
+    <RangeMap<K, (), S> as Iterable>::Iterator<'a>,
+    fn((Range<&'a K>, &'a ())) -> Range<&'a K>,
+>;
+
+impl<K, S: IntervalStorage<K, ()>> Iterable for RangeSet<K, S> {
+    type Item<'collection>
+        = Range<&'collection K>
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection, K, S>
+    where
+        Self: 'collection;
+
+    /// Iterates over the ranges in order.
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.map.iter().map(|(range, _)| range)
+    }
+}
+
+impl<K: Ord + Clone, S: IntervalStorage<K, ()>> Collection<K> for RangeSet<K, S> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &K) -> bool {
+        self.contains_point(other)
+    }
+}
+
+impl<K: Ord + Clone, S: IntervalStorage<K, ()>> CollectionMut<K> for RangeSet<K, S> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<K: Ord + Clone, S: IntervalStorage<K, ()>> Set<K> for RangeSet<K, S> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn get<'a>(&'a self, value: &'a K) -> Option<&'a K> {
+        self.contains_point(value).then_some(value)
+    }
+}
@@ -1,14 +1,43 @@
-use crate::{Collection, CollectionMut};
+use crate::{Collection, CollectionMut, Error};
+
+mod bit;
+mod interval;
+#[cfg(feature = "alloc")]
+mod ordered;
+
+#[cfg(feature = "alloc")]
+pub use bit::BitSet;
+pub use bit::{BitStorage, FixedBitSet, WordSet};
+#[cfg(feature = "heapless")]
+pub use interval::FlatIntervalSet;
+#[cfg(feature = "alloc")]
+pub use interval::IntervalSet;
+pub use interval::RangeSet;
+#[cfg(feature = "alloc")]
+pub use ordered::OrderedHashSet;
 
 pub trait Set<T>: Collection<T> {
     fn len(&self) -> usize;
-    fn get(&self, value: &T) -> Option<&T>;
+    /// Returns the stored value equal to `value`.
+    ///
+    /// Sets that do not store their values, such as bit sets, hand back
+    /// `value` itself, hence the shared lifetime.
+    fn get<'a>(&'a self, value: &'a T) -> Option<&'a T>;
 }
 
 pub trait SetMut<T>: CollectionMut<T> {
     fn insert(&mut self, value: T) -> bool;
     fn remove(&mut self, value: &T) -> bool;
     fn capacity(&mut self) -> usize;
+
+    /// Inserts a value, returning it back inside [`Error::InsertFailed`] if
+    /// the set cannot hold another element.
+    ///
+    /// Unbounded sets never fail, so the default implementation simply
+    /// forwards to [`SetMut::insert`].
+    fn try_insert(&mut self, value: T) -> Result<bool, Error<T>> {
+        Ok(self.insert(value))
+    }
 }
 
 #[cfg(feature = "std")]
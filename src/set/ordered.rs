@@ -0,0 +1,154 @@
+use core::hash::{BuildHasher, Hash};
+
+use super::{Set, SetMut};
+use crate::map::{MapMut, OrderedHashMap};
+use crate::{Collection, CollectionMut, Iterable};
+
+/// Hash set that remembers insertion order, built on [`OrderedHashMap`].
+///
+/// [`SetMut::remove`] preserves the order of the remaining values, which
+/// costs O(n); use [`OrderedHashSet::swap_remove`] for an O(1) removal that
+/// moves the last value into the gap.
+///
+/// The hasher defaults the same way as [`OrderedHashMap`]'s.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct OrderedHashSet<T, S = std::collections::hash_map::RandomState> {
+    map: OrderedHashMap<T, (), S>,
+}
+
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Clone)]
+pub struct OrderedHashSet<T, S> {
+    map: OrderedHashMap<T, (), S>,
+}
+
+#[cfg(feature = "std")]
+impl<T> OrderedHashSet<T> {
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+
+impl<T, S: Default> Default for OrderedHashSet<T, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<T, S> OrderedHashSet<T, S> {
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: OrderedHashMap::with_hasher(hasher),
+        }
+    }
+
+    /// Returns the value at position `index` in insertion order.
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.map.get_index(index).map(|(value, _)| value)
+    }
+
+    /// Removes the value at `index`, moving the last value into its place.
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.swap_remove_index(index).map(|(value, _)| value)
+    }
+
+    /// Removes the value at `index`, shifting later values down by one.
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.shift_remove_index(index).map(|(value, _)| value)
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> OrderedHashSet<T, S> {
+    /// Position of `value` in insertion order.
+    pub fn get_index_of(&self, value: &T) -> Option<usize> {
+        self.map.get_index_of(value)
+    }
+
+    /// Inserts a value, returning its position and whether it was new.
+    pub fn insert_full(&mut self, value: T) -> (usize, bool) {
+        let (index, old) = self.map.insert_full(value, ());
+        (index, old.is_none())
+    }
+
+    /// Removes `value` in O(1) by moving the last value into its place.
+    pub fn swap_remove(&mut self, value: &T) -> bool {
+        self.map.swap_remove(value).is_some()
+    }
+
+    /// Removes `value` in O(n), keeping the order of the remaining values.
+    pub fn shift_remove(&mut self, value: &T) -> bool {
+        self.map.shift_remove(value).is_some()
+    }
+}
+
+type Iter<'a, T, S> = core::iter::Map<
+    <OrderedHashMap<T, (), S> as Iterable>::Iterator<'a>,
+    fn((&'a T, &'a ())) -> &'a T,
+>;
+
+impl<T, S> Iterable for OrderedHashSet<T, S> {
+    type Item<'collection>
+        = &'collection T
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Iter<'collection, T, S>
+    where
+        Self: 'collection;
+
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.map.iter().map(|(value, _)| value)
+    }
+}
+
+impl<T, S> Collection<T> for OrderedHashSet<T, S> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|value| value == other)
+    }
+}
+
+impl<T, S> CollectionMut<T> for OrderedHashSet<T, S> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Set<T> for OrderedHashSet<T, S> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn get(&self, value: &T) -> Option<&T> {
+        self.get_index(self.get_index_of(value)?)
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> SetMut<T> for OrderedHashSet<T, S> {
+    #[inline(always)]
+    fn insert(&mut self, value: T) -> bool {
+        self.insert_full(value).1
+    }
+
+    /// Removes `value` while keeping the order of the remaining values.
+    #[inline(always)]
+    fn remove(&mut self, value: &T) -> bool {
+        self.shift_remove(value)
+    }
+
+    #[inline(always)]
+    fn capacity(&mut self) -> usize {
+        MapMut::capacity(&mut self.map)
+    }
+}
@@ -0,0 +1,278 @@
+use core::iter::Filter;
+use core::marker::PhantomData;
+
+use crate::{Collection, Iterable, Map, Set};
+
+/// The keys of a map, viewed as a [`Set`].
+#[derive(Debug)]
+pub struct KeySet<'a, K, V, M: ?Sized> {
+    map: &'a M,
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'a, K, V, M: Map<K, V> + ?Sized> KeySet<'a, K, V, M> {
+    pub fn new(map: &'a M) -> Self {
+        Self {
+            map,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, M: ?Sized> Clone for KeySet<'_, K, V, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V, M: ?Sized> Copy for KeySet<'_, K, V, M> {}
+
+/// The values of a map, viewed as a [`Collection`].
+#[derive(Debug)]
+pub struct Values<'a, K, V, M: ?Sized> {
+    map: &'a M,
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'a, K, V, M: Map<K, V> + ?Sized> Values<'a, K, V, M> {
+    pub fn new(map: &'a M) -> Self {
+        Self {
+            map,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, M: ?Sized> Clone for Values<'_, K, V, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V, M: ?Sized> Copy for Values<'_, K, V, M> {}
+
+/// The items of a collection that match a predicate.
+///
+/// Every read is filtered on the fly, so `len` is O(n).
+#[derive(Debug, Clone, Copy)]
+pub struct Filtered<'a, C: ?Sized, P> {
+    inner: &'a C,
+    predicate: P,
+}
+
+impl<'a, C: ?Sized, P> Filtered<'a, C, P> {
+    pub fn new(inner: &'a C, predicate: P) -> Self {
+        Self { inner, predicate }
+    }
+}
+
+/// The items of a collection transformed by a function.
+#[derive(Debug, Clone, Copy)]
+pub struct Mapped<'a, C: ?Sized, F> {
+    inner: &'a C,
+    f: F,
+}
+
+impl<'a, C: ?Sized, F> Mapped<'a, C, F> {
+    pub fn new(inner: &'a C, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<'a, K: 'a, V: 'a, M: Map<K, V> + ?Sized> Iterable for KeySet<'a, K, V, M> {
+    type Item<'collection>
+        = &'a K
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = M::Keys<'a>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.map.keys()
+    }
+}
+
+impl<K, V, M: Map<K, V> + ?Sized> Collection<K> for KeySet<'_, K, V, M> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[inline(always)]
+    fn contains(&self, other: &K) -> bool
+    where
+        K: PartialEq,
+    {
+        self.map.get(other).is_some()
+    }
+}
+
+impl<K: PartialEq, V, M: Map<K, V> + ?Sized> Set<K> for KeySet<'_, K, V, M> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[inline(always)]
+    fn get(&self, value: &K) -> Option<&K> {
+        self.map.get_key_value(value).map(|(k, _)| k)
+    }
+}
+
+impl<'a, K: 'a, V: 'a, M: Map<K, V> + ?Sized> Iterable for Values<'a, K, V, M> {
+    type Item<'collection>
+        = &'a V
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = M::Values<'a>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.map.values()
+    }
+}
+
+impl<K, V, M: Map<K, V> + ?Sized> Collection<V> for Values<'_, K, V, M> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn contains(&self, other: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.map.values().any(|v| v == other)
+    }
+}
+
+impl<'a, C, P> Iterable for Filtered<'a, C, P>
+where
+    C: Iterable + ?Sized + 'a,
+    P: Fn(&C::Item<'a>) -> bool,
+{
+    type Item<'collection>
+        = C::Item<'a>
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = Filter<C::Iterator<'a>, &'collection P>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.inner.iter().filter(&self.predicate)
+    }
+}
+
+impl<'a, T: 'a, C, P> Collection<T> for Filtered<'a, C, P>
+where
+    C: Iterable<Item<'a> = &'a T> + ?Sized + 'a,
+    P: Fn(&&'a T) -> bool,
+{
+    fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    fn contains(&self, other: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|x| x == other)
+    }
+}
+
+impl<'a, T: 'a, C, P> Set<T> for Filtered<'a, C, P>
+where
+    C: Set<T> + Iterable<Item<'a> = &'a T> + ?Sized + 'a,
+    P: for<'x> Fn(&&'x T) -> bool,
+{
+    fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    fn get<'b>(&'b self, value: &'b T) -> Option<&'b T> {
+        self.inner.get(value).filter(|x| (self.predicate)(x))
+    }
+}
+
+/// Iterator over the keys of a [`Filtered`] map view.
+pub type FilteredKeys<'c, 'a, I, P, K, V> =
+    core::iter::Map<Filter<I, &'c P>, fn((&'a K, &'a V)) -> &'c K>;
+
+/// Iterator over the values of a [`Filtered`] map view.
+pub type FilteredValues<'c, 'a, I, P, K, V> =
+    core::iter::Map<Filter<I, &'c P>, fn((&'a K, &'a V)) -> &'c V>;
+
+impl<'a, K: 'a, V: 'a, M, P> Map<K, V> for Filtered<'a, M, P>
+where
+    M: Map<K, V> + Iterable<Item<'a> = (&'a K, &'a V)> + ?Sized + 'a,
+    P: Fn(&(&'a K, &'a V)) -> bool,
+    K: PartialEq,
+{
+    type Keys<'key>
+        = FilteredKeys<'key, 'a, M::Iterator<'a>, P, K, V>
+    where
+        K: 'key,
+        Self: 'key;
+
+    type Values<'value>
+        = FilteredValues<'value, 'a, M::Iterator<'a>, P, K, V>
+    where
+        V: 'value,
+        Self: 'value;
+
+    fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    fn get(&self, k: &K) -> Option<&V> {
+        self.get_key_value(k).map(|(_, v)| v)
+    }
+
+    fn get_key_value(&self, k: &K) -> Option<(&K, &V)> {
+        let inner: &'a M = self.inner;
+        inner
+            .get_key_value(k)
+            .filter(|entry| (self.predicate)(entry))
+    }
+
+    fn keys<'c>(&'c self) -> Self::Keys<'c> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    fn values<'c>(&'c self) -> Self::Values<'c> {
+        self.iter().map(|(_, v)| v)
+    }
+}
+
+impl<'a, C, F, R> Iterable for Mapped<'a, C, F>
+where
+    C: Iterable + ?Sized + 'a,
+    F: Fn(C::Item<'a>) -> R,
+{
+    type Item<'collection>
+        = R
+    where
+        Self: 'collection;
+
+    type Iterator<'collection>
+        = core::iter::Map<C::Iterator<'a>, &'collection F>
+    where
+        Self: 'collection;
+
+    #[inline(always)]
+    fn iter<'c>(&'c self) -> Self::Iterator<'c> {
+        self.inner.iter().map(&self.f)
+    }
+}
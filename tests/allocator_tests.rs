@@ -0,0 +1,203 @@
+#![cfg(feature = "allocator-api")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::{Cell, UnsafeCell};
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use collections2::{
+    AllocError, AllocVec, Allocator, Error, List as _, ListDrain, ListMut, ListNew, ListResizable,
+    ListSortable,
+};
+
+thread_local! {
+    static GLOBAL_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts this thread's allocations from the global allocator, so tests
+/// running in parallel do not see each other's.
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        GLOBAL_ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        GLOBAL_ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn global_allocations() -> usize {
+    GLOBAL_ALLOCATIONS.with(Cell::get)
+}
+
+#[repr(C, align(16))]
+struct Arena<const N: usize> {
+    bytes: UnsafeCell<[u8; N]>,
+    used: AtomicUsize,
+}
+
+unsafe impl<const N: usize> Sync for Arena<N> {}
+
+impl<const N: usize> Arena<N> {
+    const fn new() -> Self {
+        Self {
+            bytes: UnsafeCell::new([0; N]),
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let base = self.bytes.get() as *mut u8;
+        let mut start = 0;
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                let addr = base as usize + used;
+                let aligned = (addr + layout.align() - 1) & !(layout.align() - 1);
+                start = aligned - base as usize;
+                start.checked_add(layout.size()).filter(|&end| end <= N)
+            })
+            .map_err(|_| AllocError)?;
+        let ptr = unsafe { base.add(start) };
+        NonNull::new(ptr::slice_from_raw_parts_mut(ptr, layout.size())).ok_or(AllocError)
+    }
+}
+
+/// A borrowed arena is an allocator too, one that cannot be made on demand.
+unsafe impl<const N: usize> Allocator for Arena<N> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Arena::allocate(self, layout)
+    }
+
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+}
+
+/// Bump allocators over static arenas: every allocation moves a cursor
+/// forward and nothing is ever freed. Each test gets its own arena.
+macro_rules! bump {
+    ($name:ident, $size:literal) => {
+        #[derive(Clone, Copy, Default)]
+        struct $name;
+
+        unsafe impl Allocator for $name {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+                static ARENA: Arena<$size> = Arena::new();
+                ARENA.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+        }
+    };
+}
+
+bump!(Roomy, 4096);
+bump!(Large, 98304);
+bump!(Tiny, 64);
+
+#[test]
+fn test_list_ops_stay_in_allocator() {
+    let before = global_allocations();
+
+    let mut list: AllocVec<u32, Roomy> = ListNew::new();
+    for i in 0..10 {
+        ListMut::push(&mut list, i).unwrap();
+    }
+    ListMut::insert(&mut list, 0, 42).unwrap();
+    ListDrain::extend_from_slice(&mut list, &[7, 8, 9]).unwrap();
+    ListResizable::resize(&mut list, 20, 0).unwrap();
+    ListSortable::sort(&mut list);
+    let drained = ListDrain::drain(&mut list, ..5).sum::<u32>();
+    let spliced = ListDrain::splice(&mut list, 0..2, [100, 200, 300])
+        .unwrap()
+        .count();
+    let mut tail = ListMut::split_off(&mut list, 10);
+    ListMut::append(&mut list, &mut tail).unwrap();
+
+    assert_eq!(global_allocations(), before);
+    assert_eq!(drained, 0);
+    assert_eq!(spliced, 2);
+    assert_eq!(list.len(), 16);
+    assert_eq!(list.first(), Some(&100));
+    assert_eq!(list.last(), Some(&42));
+}
+
+#[test]
+fn test_stable_sort_of_long_list_stays_in_allocator() {
+    let mut list = AllocVec::with_capacity_in(10_000, Large);
+    for i in 0..10_000u32 {
+        ListMut::push(&mut list, (i.wrapping_mul(7919) % 100, i)).unwrap();
+    }
+
+    let before = global_allocations();
+    ListSortable::sort_by_key(&mut list, |&(key, _)| key);
+    assert_eq!(global_allocations(), before);
+
+    assert!(list.windows(2).all(|w| w[0] <= w[1]));
+    ListSortable::sort_by(&mut list, |a, b| b.1.cmp(&a.1));
+    ListSortable::sort(&mut list);
+    assert_eq!(global_allocations(), before);
+    assert!(list.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_exhausted_allocator_returns_error() {
+    let mut list: AllocVec<u64, Tiny> = ListNew::new();
+    let mut pushed = 0;
+    let err = loop {
+        match ListMut::push(&mut list, pushed) {
+            Ok(()) => pushed += 1,
+            Err(err) => break err,
+        }
+    };
+
+    assert!(pushed > 0);
+    assert_eq!(err, Error::InsertFailed(pushed));
+    assert_eq!(list.len(), pushed as usize);
+    assert_eq!(
+        ListDrain::extend_from_slice(&mut list, &[1; 8]),
+        Err(Error::CapacityExceeded)
+    );
+    assert_eq!(
+        ListResizable::resize(&mut list, 16, 0),
+        Err(Error::CapacityExceeded)
+    );
+    assert_eq!(
+        ListResizable::resize_with(&mut list, 16, || 0),
+        Err(Error::CapacityExceeded)
+    );
+    assert_eq!(list.len(), pushed as usize);
+}
+
+#[test]
+fn test_borrowed_arena_handle() {
+    fn fill<L: ListResizable<u32> + ListSortable<u32> + ListDrain<u32>>(list: &mut L) {
+        for i in (0..6).rev() {
+            list.push(i).unwrap();
+        }
+        list.resize(8, 9).unwrap();
+        list.sort();
+        assert_eq!(list.extract_if(.., |x| *x % 2 == 1).count(), 5);
+    }
+
+    let arena = Arena::<1024>::new();
+    let before = global_allocations();
+
+    let mut list = AllocVec::new_in(&arena);
+    fill(&mut list);
+    let tail = ListMut::split_off(&mut list, 1);
+
+    assert_eq!(global_allocations(), before);
+    assert!(arena.used.load(Ordering::Relaxed) > 0);
+    assert_eq!(list.as_slice(), &[0]);
+    assert_eq!(tail.as_slice(), &[2, 4]);
+}
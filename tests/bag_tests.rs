@@ -0,0 +1,89 @@
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use collections2::{Bag, BagMut};
+
+#[cfg(feature = "alloc")]
+fn count_words<B: BagMut<&'static str>>(bag: &mut B, text: &'static str) {
+    for word in text.split_whitespace() {
+        bag.insert_n(word, 1).unwrap();
+    }
+}
+
+#[cfg(feature = "std")]
+mod hashmap_tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_count_and_remove() {
+        let mut bag = HashMap::new();
+        count_words(&mut bag, "a b a c a");
+
+        assert_eq!(bag.count(&"a"), 3);
+        assert_eq!(bag.count(&"z"), 0);
+        assert_eq!(bag.distinct_len(), 3);
+        assert_eq!(bag.total_len(), 5);
+
+        assert_eq!(bag.remove_n(&"a", 2), 2);
+        assert_eq!(bag.remove_n(&"a", 5), 1);
+        assert_eq!(bag.count(&"a"), 0);
+        assert_eq!(bag.distinct_len(), 2);
+    }
+
+    #[test]
+    fn test_total_len_saturates() {
+        let mut bag = HashMap::new();
+        bag.insert_n("a", usize::MAX).unwrap();
+        bag.insert_n("b", 2).unwrap();
+
+        assert_eq!(bag.total_len(), usize::MAX);
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod btreemap_tests {
+    extern crate alloc;
+    use alloc::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn test_union_and_intersection() {
+        let mut a = BTreeMap::new();
+        let mut b = BTreeMap::new();
+        count_words(&mut a, "x x y");
+        count_words(&mut b, "x y y z");
+
+        let mut union = BTreeMap::new();
+        a.union_into(&b, &mut union).unwrap();
+        assert_eq!(
+            union.counts().collect::<Vec<_>>(),
+            vec![(&"x", 2), (&"y", 2), (&"z", 1)]
+        );
+
+        let mut intersection = BTreeMap::new();
+        a.intersection_into(&b, &mut intersection).unwrap();
+        assert_eq!(
+            intersection.counts().collect::<Vec<_>>(),
+            vec![(&"x", 1), (&"y", 1)]
+        );
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+    use collections2::{Error, FlatMap};
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_capacity_bag() {
+        let mut bag = FlatMap::<u8, usize, heapless::Vec<(u8, usize), 2>>::new();
+
+        assert_eq!(bag.insert_n(1, 2), Ok(2));
+        assert_eq!(bag.insert_n(2, 1), Ok(1));
+        assert_eq!(bag.insert_n(1, 1), Ok(3));
+        assert_eq!(bag.insert_n(3, 1), Err(Error::InsertFailed(3)));
+        assert_eq!(bag.total_len(), 4);
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(feature = "std")]
+mod hash_tests {
+    use collections2::{BidiMap, BidiMapMut, HashBiMap, Overwritten};
+
+    #[test]
+    fn test_lookup_both_directions() {
+        let mut map = HashBiMap::new();
+        assert_eq!(map.insert(1, "one"), Ok(Overwritten::Neither));
+        assert_eq!(map.insert(2, "two"), Ok(Overwritten::Neither));
+
+        assert_eq!(map.get_by_left(&1), Some(&"one"));
+        assert_eq!(map.get_by_right(&"two"), Some(&2));
+        assert_eq!(map.by_left().get(&2), Some(&"two"));
+        assert_eq!(map.by_right().len(), 2);
+    }
+
+    #[test]
+    fn test_insert_reports_overwritten_pairs() {
+        let mut map = HashBiMap::new();
+        map.insert(1, "one").unwrap();
+        map.insert(2, "two").unwrap();
+
+        assert_eq!(map.insert(1, "one"), Ok(Overwritten::Pair(1, "one")));
+        assert_eq!(map.insert(1, "uno"), Ok(Overwritten::Left(1, "one")));
+        assert_eq!(map.insert(3, "two"), Ok(Overwritten::Right(2, "two")));
+        assert_eq!(
+            map.insert(1, "two"),
+            Ok(Overwritten::Both((1, "uno"), (3, "two")))
+        );
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get_by_right(&"uno"), None);
+    }
+
+    #[test]
+    fn test_remove_keeps_sides_in_sync() {
+        let mut map = HashBiMap::new();
+        map.insert(1, "one").unwrap();
+        map.insert(2, "two").unwrap();
+
+        assert_eq!(map.remove_by_left(&1), Some((1, "one")));
+        assert_eq!(map.get_by_right(&"one"), None);
+        assert_eq!(map.remove_by_right(&"two"), Some((2, "two")));
+        assert!(map.is_empty());
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+    use collections2::{BiMap, BidiMap, BidiMapMut, Error, FlatMap};
+
+    type FixedBiMap = BiMap<
+        u8,
+        char,
+        FlatMap<u8, char, heapless::Vec<(u8, char), 2>>,
+        FlatMap<char, u8, heapless::Vec<(char, u8), 2>>,
+    >;
+
+    #[test]
+    fn test_full_map_rejects_new_pair() {
+        let mut map = FixedBiMap::new();
+        map.insert(1, 'a').unwrap();
+        map.insert(2, 'b').unwrap();
+
+        assert_eq!(map.insert(3, 'c'), Err(Error::InsertFailed((3, 'c'))));
+        // Replacing a pair frees a slot first, so it still succeeds.
+        assert!(map.insert(2, 'c').is_ok());
+        assert_eq!(map.get_by_right(&'c'), Some(&2));
+        assert_eq!(map.len(), 2);
+    }
+}
@@ -0,0 +1,80 @@
+use collections2::{BitArray, Collection, Error, Iterable, List, ListMut, ListNew};
+
+fn bits<L: ListNew<bool>>(values: &[bool]) -> L {
+    let mut list = L::new();
+    for value in values {
+        list.push(*value).unwrap();
+    }
+    list
+}
+
+#[test]
+fn test_push_get_and_slice() {
+    let list: BitArray<1> = bits(&[true, false, true]);
+
+    assert_eq!(list.get(0), Some(&true));
+    assert_eq!(list.get(1), Some(&false));
+    assert_eq!(list.get(3), None);
+    assert_eq!(list.last(), Some(&true));
+    assert_eq!(list.as_slice(), &[true, false, true][..]);
+    assert_eq!(list.iter().filter(|bit| *bit).count(), 2);
+}
+
+#[test]
+fn test_get_mut_writes_back() {
+    let mut list: BitArray<1> = bits(&[false, false]);
+
+    if let Some(mut bit) = list.get_mut(1) {
+        *bit = true;
+    }
+    assert_eq!(list.set(0, true), Ok(false));
+    assert_eq!(list.set(2, true), Err(Error::InsertFailed(true)));
+    assert_eq!(list.as_slice(), &[true, true][..]);
+}
+
+#[test]
+fn test_fixed_capacity() {
+    let mut list = BitArray::<1>::new();
+    for i in 0..64 {
+        list.push(i % 3 == 0).unwrap();
+    }
+    assert_eq!(list.push(true), Err(Error::InsertFailed(true)));
+    assert_eq!(list.insert(0, true), Err(Error::InsertFailed(true)));
+    assert_eq!(list.pop(), Some(true));
+    assert_eq!(list.count_ones(), 21);
+}
+
+#[test]
+fn test_reordering_operations() {
+    let mut list: BitArray<2> = bits(&[true, true, false, false, true]);
+
+    list.insert(1, false).unwrap();
+    assert_eq!(
+        list.as_slice(),
+        &[true, false, true, false, false, true][..]
+    );
+    assert!(list.remove(0));
+    list.dedup();
+    assert_eq!(list.as_slice(), &[false, true, false, true][..]);
+    list.retain(|bit| *bit);
+    assert_eq!(list.len(), 2);
+
+    let mut tail = list.split_off(1);
+    tail.push(false).unwrap();
+    list.append(&mut tail).unwrap();
+    list.reverse();
+    assert_eq!(list.as_slice(), &[false, true, true][..]);
+    assert_eq!(list.binary_search(&true), Ok(1));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_bit_list_grows() {
+    use collections2::BitList;
+
+    let mut list: BitList = bits(&[true; 100]);
+    list.set(99, false).unwrap();
+    assert_eq!(list.len(), 100);
+    assert_eq!(list.count_ones(), 99);
+    assert!(list.ends_with(&[true, false]));
+}
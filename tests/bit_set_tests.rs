@@ -0,0 +1,79 @@
+use collections2::{Error, FixedBitSet, Iterable, Set, SetMut};
+
+#[test]
+fn test_insert_remove_contains() {
+    let mut set = FixedBitSet::<2>::new();
+    assert_eq!(set.insert(3), Ok(true));
+    assert_eq!(set.insert(3), Ok(false));
+    assert_eq!(set.insert(127), Ok(true));
+    assert_eq!(set.insert(128), Err(Error::InsertFailed(128)));
+
+    assert!(set.contains(127));
+    assert!(set.remove(3));
+    assert!(!set.remove(3));
+    assert_eq!(set.count_ones(), 1);
+}
+
+#[test]
+fn test_iter_is_ascending() {
+    let mut set = FixedBitSet::<3>::new();
+    for value in [130, 0, 64, 63, 5] {
+        set.insert(value).unwrap();
+    }
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0, 5, 63, 64, 130]);
+}
+
+#[test]
+fn test_union_and_intersection() {
+    let mut a = FixedBitSet::<1>::new();
+    let mut b = FixedBitSet::<2>::new();
+    a.insert(1).unwrap();
+    a.insert(2).unwrap();
+    b.insert(2).unwrap();
+    b.insert(3).unwrap();
+
+    a.union_with(&b).unwrap();
+    assert_eq!(a.iter().collect::<Vec<_>>(), [1, 2, 3]);
+    b.insert(100).unwrap();
+    assert_eq!(a.union_with(&b), Err(Error::CapacityExceeded));
+    a.intersect_with(&b);
+    assert_eq!(a.iter().collect::<Vec<_>>(), [2, 3]);
+}
+
+#[test]
+fn test_set_traits() {
+    fn fill<S: SetMut<usize>>(set: &mut S) {
+        set.insert(7);
+        set.insert(9);
+        set.remove(&7);
+    }
+
+    let mut set = FixedBitSet::<1>::new();
+    fill(&mut set);
+    assert_eq!(Set::len(&set), 1);
+    assert_eq!(Set::get(&set, &9), Some(&9));
+    assert_eq!(Set::get(&set, &10), None);
+    assert_eq!(set.try_insert(64), Err(Error::InsertFailed(64)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_bit_set_grows() {
+    use collections2::BitSet;
+
+    let mut set = BitSet::new();
+    set.insert(1000).unwrap();
+    assert!(set.bit_capacity() > 1000);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [1000]);
+}
+
+#[test]
+fn test_equality_ignores_spare_words() {
+    let mut a = FixedBitSet::<1>::new();
+    let mut b = FixedBitSet::<4>::new();
+    a.insert(9).unwrap();
+    b.insert(9).unwrap();
+    assert_eq!(a, b);
+    b.insert(200).unwrap();
+    assert_ne!(a, b);
+}
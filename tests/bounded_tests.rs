@@ -0,0 +1,161 @@
+#[cfg(feature = "alloc")]
+mod vec_tests {
+    use collections2::{
+        Bounded, Collection as _, Error, List as _, ListDrain, ListMut, ListResizable,
+    };
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    fn new_list<T: PartialEq + core::fmt::Debug>(limit: usize) -> Bounded<Vec<T>> {
+        Bounded::with_limit(Vec::new(), limit)
+    }
+
+    #[test]
+    fn test_push_at_limit() {
+        let mut vec = new_list::<u32>(2);
+
+        assert!(vec.push(1).is_ok());
+        assert!(vec.push(2).is_ok());
+        assert_eq!(vec.push(3), Err(Error::InsertFailed(3)));
+        assert_eq!(vec.insert(0, 4), Err(Error::InsertFailed(4)));
+
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_append_exceeds_limit() {
+        let mut vec1 = new_list::<u32>(3);
+        let mut vec2 = new_list::<u32>(3);
+
+        vec1.push(1).unwrap();
+        vec1.push(2).unwrap();
+        vec2.push(3).unwrap();
+        vec2.push(4).unwrap();
+
+        assert_eq!(vec1.append(&mut vec2), Err(Error::CapacityExceeded));
+        assert_eq!(vec1.as_slice(), &[1, 2]);
+        assert_eq!(vec2.as_slice(), &[3, 4]);
+    }
+
+    #[test]
+    fn test_resize_exceeds_limit() {
+        let mut vec = new_list::<u32>(3);
+
+        assert!(vec.resize(3, 7).is_ok());
+        assert_eq!(vec.resize(4, 8), Err(Error::CapacityExceeded));
+        assert_eq!(vec.resize_with(4, || 8), Err(Error::CapacityExceeded));
+        assert!(vec.resize(1, 0).is_ok());
+        assert_eq!(vec.as_slice(), &[7]);
+    }
+
+    #[test]
+    fn test_splice_and_extend_respect_limit() {
+        let mut vec = new_list::<u32>(4);
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+
+        assert_eq!(vec.extend_from_slice(&[4, 5]), Err(Error::CapacityExceeded));
+        assert_eq!(vec.extend_from_within(1..), Err(Error::CapacityExceeded));
+        assert_eq!(
+            vec.splice(..1, [7, 8, 9]).err(),
+            Some(Error::CapacityExceeded)
+        );
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+
+        assert_eq!(vec.splice(..2, [7, 8, 9]).unwrap().count(), 2);
+        assert_eq!(vec.as_slice(), &[7, 8, 9, 3]);
+    }
+
+    #[test]
+    fn test_split_off_keeps_limit() {
+        let mut vec = new_list::<u32>(2);
+        vec.push(1).unwrap();
+        vec.push(2).unwrap();
+
+        let mut tail = vec.split_off(1);
+        assert_eq!(tail.limit(), 2);
+        assert!(tail.push(3).is_ok());
+        assert_eq!(tail.push(4), Err(Error::InsertFailed(4)));
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+    use collections2::{Bounded, Collection as _, Error, List as _, ListDrain, ListMut};
+
+    type Small = heapless::Vec<u32, 4>;
+
+    #[test]
+    fn test_limit_below_inner_capacity() {
+        let mut vec = Bounded::with_limit(Small::new(), 2);
+        assert_eq!(ListMut::capacity(&vec), 2);
+
+        vec.push(1).unwrap();
+        vec.push(2).unwrap();
+        assert_eq!(vec.push(3), Err(Error::InsertFailed(3)));
+        assert_eq!(
+            ListDrain::extend_from_slice(&mut vec, &[3]),
+            Err(Error::CapacityExceeded)
+        );
+        assert_eq!(vec.as_slice(), &[1, 2]);
+
+        vec.set_limit(3);
+        vec.push(3).unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_inner_capacity_below_limit() {
+        let mut vec = Bounded::with_limit(Small::new(), 10);
+        assert_eq!(ListMut::capacity(&vec), 4);
+
+        ListDrain::extend_from_slice(&mut vec, &[1, 2, 3]).unwrap();
+        assert_eq!(
+            ListDrain::extend_from_slice(&mut vec, &[4, 5]),
+            Err(Error::CapacityExceeded)
+        );
+        vec.push(4).unwrap();
+        assert_eq!(vec.push(5), Err(Error::InsertFailed(5)));
+        assert_eq!(vec.insert(0, 6), Err(Error::InsertFailed(6)));
+        assert_eq!(
+            ListDrain::splice(&mut vec, ..1, [7, 8]).err(),
+            Some(Error::CapacityExceeded)
+        );
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+    }
+}
+
+#[cfg(feature = "std")]
+mod map_set_tests {
+    use std::collections::{HashMap, HashSet};
+
+    use collections2::{Bounded, Error, Map as _, MapMut, SetMut};
+
+    #[test]
+    fn test_map_try_insert_at_limit() {
+        let mut map = Bounded::with_limit(HashMap::new(), 1);
+
+        assert_eq!(map.try_insert("a", 1), Ok(None));
+        // Replacing an existing key does not grow the map.
+        assert_eq!(map.try_insert("a", 2), Ok(Some(1)));
+        assert_eq!(map.try_insert("b", 3), Err(Error::InsertFailed(("b", 3))));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_map_insert_panics_at_limit() {
+        let mut map = Bounded::with_limit(HashMap::new(), 0);
+        map.insert(1, 1);
+    }
+
+    #[test]
+    fn test_set_try_insert_at_limit() {
+        let mut set = Bounded::with_limit(HashSet::new(), 1);
+
+        assert_eq!(set.try_insert(1), Ok(true));
+        assert_eq!(set.try_insert(1), Ok(false));
+        assert_eq!(set.try_insert(2), Err(Error::InsertFailed(2)));
+    }
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "std")]
+
+use std::collections::{BTreeMap, HashMap};
+
+use collections2::{
+    Collection, CollectionMut, CowList, CowMap, Error, GetMut, List, ListMut, Map, MapMut,
+};
+
+#[test]
+fn test_list_reads_without_cloning() {
+    let data = [1, 2, 3, 4];
+    let list: CowList<'_, i32, Vec<i32>> = CowList::borrowed(&data);
+
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.as_slice(), &[1, 2, 3, 4][..]);
+    assert_eq!(list.binary_search(&3), Ok(2));
+    assert_eq!(list.last(), Some(&4));
+    assert!(list.contains(&2));
+    assert!(!list.is_owned());
+    assert!(!list.is_cloned());
+}
+
+#[test]
+fn test_list_clones_on_first_mutation() {
+    let data = [3, 1, 2];
+    let mut list: CowList<'_, i32, Vec<i32>> = CowList::borrowed(&data);
+
+    list.push(4).unwrap();
+    assert!(list.is_owned());
+    assert!(list.is_cloned());
+    *list.get_mut(0).unwrap() = 0;
+    list.reverse();
+
+    assert_eq!(list.as_slice(), &[4, 2, 1, 0][..]);
+    assert_eq!(data, [3, 1, 2]);
+    assert_eq!(list.into_owned(), vec![4, 2, 1, 0]);
+}
+
+#[test]
+fn test_list_clear_and_owned_do_not_clone() {
+    let data = [1, 2, 3];
+    let mut list: CowList<'_, i32, Vec<i32>> = CowList::borrowed(&data);
+    list.clear();
+    assert!(list.is_owned());
+    assert!(!list.is_cloned());
+    assert!(list.is_empty());
+
+    let mut list: CowList<'_, i32, Vec<i32>> = CowList::owned(vec![1]);
+    list.push(2).unwrap();
+    assert!(!list.is_cloned());
+}
+
+#[test]
+fn test_list_pop_on_empty_slice_does_not_clone() {
+    let mut list: CowList<'_, i32, Vec<i32>> = CowList::borrowed(&[]);
+    assert_eq!(list.pop(), None);
+    assert!(!list.is_owned());
+
+    let data = [1];
+    let mut list: CowList<'_, i32, Vec<i32>> = CowList::borrowed(&data);
+    assert_eq!(list.pop(), Some(1));
+    assert!(list.is_cloned());
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_list_clone_into_fixed_backend_can_fail() {
+    let data = [1, 2, 3];
+    let mut list: CowList<'_, i32, heapless::Vec<i32, 2>> = CowList::borrowed(&data);
+
+    assert_eq!(list.push(4), Err(Error::InsertFailed(4)));
+    assert_eq!(list.try_to_mut().err(), Some(Error::CapacityExceeded));
+    assert!(!list.is_owned());
+    assert_eq!(list.as_slice(), &[1, 2, 3][..]);
+}
+
+#[test]
+fn test_list_append_and_split_off() {
+    let left = [1, 2];
+    let right = [3, 4];
+    let mut a: CowList<'_, i32, Vec<i32>> = CowList::borrowed(&left);
+    let mut b: CowList<'_, i32, Vec<i32>> = CowList::borrowed(&right);
+
+    a.append(&mut b).unwrap();
+    assert_eq!(a.as_slice(), &[1, 2, 3, 4][..]);
+    assert!(b.is_empty());
+
+    let tail = a.split_off(3);
+    assert!(tail.is_owned());
+    assert_eq!(tail.as_slice(), &[4][..]);
+    assert_eq!(a.as_slice(), &[1, 2, 3][..]);
+}
+
+#[test]
+fn test_map_reads_without_cloning() {
+    let source: BTreeMap<&str, u32> = [("a", 1), ("b", 2)].into_iter().collect();
+    let map: CowMap<'_, _, _, _, HashMap<&str, u32>> = CowMap::borrowed(&source);
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&"a"), Some(&1));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(map.values().sum::<u32>(), 3);
+    assert!(!map.is_cloned());
+}
+
+#[test]
+fn test_map_clones_on_first_mutation() {
+    let source: BTreeMap<&str, u32> = [("a", 1), ("b", 2)].into_iter().collect();
+    let mut map: CowMap<'_, _, _, _, HashMap<&str, u32>> = CowMap::borrowed(&source);
+
+    assert_eq!(map.insert("c", 3), None);
+    assert!(map.is_cloned());
+    *GetMut::get_mut(&mut map, &"a").unwrap() = 10;
+    assert_eq!(map.remove(&"b"), Some(2));
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&"a"), Some(&10));
+    assert_eq!(source.get(&"a"), Some(&1));
+    assert_eq!(source.len(), 2);
+
+    let owned = map.into_owned();
+    assert_eq!(owned.get(&"c"), Some(&3));
+}
+
+#[test]
+fn test_map_misses_do_not_clone() {
+    let source: BTreeMap<&str, u32> = [("a", 1)].into_iter().collect();
+    let mut map: CowMap<'_, _, _, _, HashMap<&str, u32>> = CowMap::borrowed(&source);
+
+    assert_eq!(map.remove(&"z"), None);
+    assert_eq!(GetMut::get_mut(&mut map, &"z"), None);
+    assert!(!map.is_owned());
+    assert_eq!(map.remove(&"a"), Some(1));
+    assert!(map.is_cloned());
+}
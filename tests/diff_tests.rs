@@ -0,0 +1,137 @@
+#![cfg(feature = "alloc")]
+
+use collections2::{diff, lcs, Bounded, Edit, Error, List};
+
+#[test]
+fn test_diff_produces_shortest_script() {
+    let a = vec!['a', 'b', 'c', 'a', 'b', 'b', 'a'];
+    let b = vec!['c', 'b', 'a', 'b', 'a', 'c'];
+    let patch = diff(&a, &b);
+
+    let changes: usize = patch
+        .edits()
+        .iter()
+        .map(|edit| match edit {
+            Edit::Keep(_) => 0,
+            Edit::Delete(n) => *n,
+            Edit::Insert(items) => items.len(),
+        })
+        .sum();
+    assert_eq!(changes, 5);
+    assert_eq!(patch.source_len(), 7);
+    assert_eq!(patch.target_len(), 6);
+
+    let mut target = a.clone();
+    patch.apply_to(&mut target).unwrap();
+    assert_eq!(target, b);
+}
+
+#[test]
+fn test_diff_edge_cases() {
+    let empty: Vec<u8> = Vec::new();
+    let full = vec![1u8, 2, 3];
+
+    assert!(diff(&empty, &empty).edits().is_empty());
+    assert!(diff(&full, &full).is_empty());
+    assert_eq!(diff(&full, &full).edits(), [Edit::Keep(3)]);
+    assert_eq!(diff(&empty, &full).edits(), [Edit::Insert(vec![1, 2, 3])]);
+    assert_eq!(diff(&full, &empty).edits(), [Edit::Delete(3)]);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_patch_across_backends() {
+    let server = vec![1, 2, 3, 4, 5, 6];
+    let mut device: heapless::Vec<i32, 6> = heapless::Vec::from_slice(&[1, 3, 4, 7]).unwrap();
+
+    let patch = diff(&device, &server);
+    patch.apply_to(&mut device).unwrap();
+    assert_eq!(device.as_slice(), &server[..]);
+}
+
+#[test]
+fn test_patch_reports_overflow() {
+    let a = vec![1, 2];
+    let b = vec![0, 1, 2, 3];
+    let patch = diff(&a, &b);
+
+    let mut bounded = Bounded::with_limit(a.clone(), 3);
+    assert_eq!(patch.apply_to(&mut bounded), Err(Error::InsertFailed(3)));
+    assert_eq!(bounded.as_slice(), &[0, 1, 2][..]);
+}
+
+#[test]
+fn test_lcs_pairs() {
+    let a = vec!["x", "a", "b", "y", "c"];
+    let b = vec!["a", "z", "b", "c"];
+    let pairs = lcs(&a, &b);
+
+    assert_eq!(pairs, [(1, 0), (2, 2), (4, 3)]);
+    assert!(pairs.iter().all(|&(i, j)| a[i] == b[j]));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_patch_is_serializable() {
+    fn assert_serde<T: serde::Serialize + serde::de::DeserializeOwned>() {}
+    assert_serde::<collections2::Patch<String>>();
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_deserialize_rejects_inconsistent_runs() {
+    use collections2::{Edit, Patch};
+
+    let patch: Patch<u8> = serde_json::from_str(
+        r#"{"source_len":3,"edits":[{"Keep":1},{"Insert":[9]},{"Delete":2}]}"#,
+    )
+    .unwrap();
+    assert_eq!(patch.edits()[1], Edit::Insert(vec![9]));
+    assert_eq!(patch.target_len(), 2);
+
+    for json in [
+        r#"{"source_len":2,"edits":[{"Keep":5},{"Delete":1}]}"#,
+        r#"{"source_len":2,"edits":[{"Keep":1}]}"#,
+        r#"{"source_len":0,"edits":[{"Keep":18446744073709551615},{"Delete":1}]}"#,
+    ] {
+        assert!(serde_json::from_str::<Patch<u8>>(json).is_err(), "{json}");
+    }
+}
+
+#[test]
+fn test_diff_matches_dynamic_programming() {
+    // Length of the longest common subsequence, the slow way.
+    fn lcs_len(a: &[u8], b: &[u8]) -> usize {
+        let mut row = vec![0; b.len() + 1];
+        for x in a {
+            let mut diag = 0;
+            for (j, y) in b.iter().enumerate() {
+                let up = row[j + 1];
+                row[j + 1] = if x == y { diag + 1 } else { up.max(row[j]) };
+                diag = up;
+            }
+        }
+        row[b.len()]
+    }
+
+    let mut seed = 0x2545_f491_u32;
+    let mut next = move |bound: u32| {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        (seed % bound) as u8
+    };
+    for _ in 0..300 {
+        let a: Vec<u8> = (0..next(20)).map(|_| next(4)).collect();
+        let b: Vec<u8> = (0..next(20)).map(|_| next(4)).collect();
+
+        let pairs = lcs(&a, &b);
+        assert_eq!(pairs.len(), lcs_len(&a, &b), "{a:?} -> {b:?}");
+        assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+        assert!(pairs.iter().all(|&(i, j)| a[i] == b[j]));
+
+        let mut target = a.clone();
+        diff(&a, &b).apply_to(&mut target).unwrap();
+        assert_eq!(target, b);
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "alloc")]
+mod vec_tests {
+    use collections2::{FlatMap, Iterable, IterableMut, Map, MapMut};
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    type VecMap<K, V> = FlatMap<K, V, Vec<(K, V)>>;
+
+    #[test]
+    fn test_insert_get_remove() {
+        let mut map = VecMap::new();
+
+        assert_eq!(map.insert(2, "two"), None);
+        assert_eq!(map.insert(1, "one"), None);
+        assert_eq!(map.insert(2, "deux"), Some("two"));
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&2), Some(&"deux"));
+        assert_eq!(map.get(&3), None);
+        assert_eq!(map.remove(&1), Some("one"));
+        assert_eq!(map.remove(&1), None);
+    }
+
+    #[test]
+    fn test_iteration_is_ordered() {
+        let mut map = VecMap::new();
+        for k in [5, 3, 9, 1] {
+            map.insert(k, k * 10);
+        }
+
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![1, 3, 5, 9]);
+        assert_eq!(map.iter().next_back(), Some((&9, &90)));
+
+        for (_, v) in map.iter_mut() {
+            *v += 1;
+        }
+        for v in map.values_mut() {
+            *v += 1;
+        }
+        assert_eq!(
+            map.values().copied().collect::<Vec<_>>(),
+            vec![12, 32, 52, 92]
+        );
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+    use collections2::{Error, FlatMap, Map, MapMut};
+
+    #[test]
+    fn test_try_insert_at_capacity() {
+        let mut map = FlatMap::<u8, u32, heapless::Vec<(u8, u32), 2>>::new();
+
+        assert_eq!(map.try_insert(1, 10), Ok(None));
+        assert_eq!(map.try_insert(2, 20), Ok(None));
+        // Replacing an existing key still works when full.
+        assert_eq!(map.try_insert(2, 21), Ok(Some(20)));
+        assert_eq!(map.try_insert(3, 30), Err(Error::InsertFailed((3, 30))));
+        assert_eq!(map.len(), 2);
+    }
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "alloc")]
+
+use collections2::{IntervalMap, IntervalSet, Iterable, Map, Set};
+
+fn ranges<V: Copy>(map: &IntervalMap<u32, V>) -> Vec<(u32, u32, V)> {
+    map.iter()
+        .map(|(range, value)| (*range.start, *range.end, *value))
+        .collect()
+}
+
+#[test]
+fn test_insert_splits_and_merges() {
+    let mut map = IntervalMap::new();
+    map.insert_range(0..10, 'a').unwrap();
+    map.insert_range(3..5, 'b').unwrap();
+    assert_eq!(ranges(&map), [(0, 3, 'a'), (3, 5, 'b'), (5, 10, 'a')]);
+
+    // Overwriting the middle with the outer value joins all three.
+    map.insert_range(2..6, 'a').unwrap();
+    assert_eq!(ranges(&map), [(0, 10, 'a')]);
+
+    // Adjacent ranges with equal values are joined too.
+    map.insert_range(10..12, 'a').unwrap();
+    map.insert_range(12..14, 'c').unwrap();
+    assert_eq!(ranges(&map), [(0, 12, 'a'), (12, 14, 'c')]);
+}
+
+#[test]
+fn test_point_lookup() {
+    let mut map = IntervalMap::new();
+    map.insert_range(10..20, "low").unwrap();
+    map.insert_range(30..40, "high").unwrap();
+
+    assert_eq!(map.get_point(&10), Some(&"low"));
+    assert_eq!(map.get_point(&19), Some(&"low"));
+    assert_eq!(map.get_point(&20), None);
+    assert_eq!(map.get_interval(&35), Some((&30..&40, &"high")));
+    assert_eq!(Map::get(&map, &5), None);
+    assert_eq!(map.get_key_value(&33), Some((&30, &"high")));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [10, 30]);
+}
+
+#[test]
+fn test_overlapping_and_gaps() {
+    let mut map = IntervalMap::new();
+    map.insert_range(0..5, 1).unwrap();
+    map.insert_range(8..10, 2).unwrap();
+    map.insert_range(12..20, 3).unwrap();
+
+    let found: Vec<_> = map.overlapping(&(4..12)).map(|(_, _, v)| *v).collect();
+    assert_eq!(found, [1, 2]);
+    assert_eq!(map.overlapping(&(5..8)).count(), 0);
+
+    let gaps: Vec<_> = map.gaps(&(2..25)).collect();
+    assert_eq!(gaps, [5..8, 10..12, 20..25]);
+    assert_eq!(map.gaps(&(13..15)).count(), 0);
+}
+
+#[test]
+fn test_remove_range() {
+    let mut map = IntervalMap::new();
+    map.insert_range(0..10, 'a').unwrap();
+    map.insert_range(10..20, 'b').unwrap();
+
+    map.remove_range(5..15).unwrap();
+    assert_eq!(ranges(&map), [(0, 5, 'a'), (15, 20, 'b')]);
+    map.remove_range(16..18).unwrap();
+    assert_eq!(ranges(&map), [(0, 5, 'a'), (15, 16, 'b'), (18, 20, 'b')]);
+}
+
+#[test]
+fn test_interval_set() {
+    let mut set = IntervalSet::new();
+    set.insert_range(0..4).unwrap();
+    set.insert_range(6..8).unwrap();
+    set.insert_range(3..6).unwrap();
+
+    assert_eq!(Set::len(&set), 1);
+    assert!(set.contains_point(&7));
+    assert_eq!(set.get(&8), None);
+    set.remove_range(2..3).unwrap();
+    assert_eq!(set.iter().collect::<Vec<_>>(), [&0..&2, &3..&8]);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_flat_capacity() {
+    use collections2::{Error, FlatIntervalMap};
+
+    let mut map = FlatIntervalMap::<u32, char, 2>::new();
+    map.insert_range(0..10, 'a').unwrap();
+    map.insert_range(20..30, 'c').unwrap();
+    assert_eq!(
+        map.insert_range(3..5, 'b'),
+        Err(Error::InsertFailed((3..5, 'b')))
+    );
+    assert_eq!(map.remove_range(3..5), Err(Error::CapacityExceeded));
+    assert_eq!(map.len(), 2);
+
+    map.remove_range(20..30).unwrap();
+    map.insert_range(5..10, 'b').unwrap();
+    assert_eq!(map.get_point(&7), Some(&'b'));
+    assert_eq!(map.len(), 2);
+}
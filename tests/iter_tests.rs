@@ -0,0 +1,52 @@
+#![cfg(feature = "std")]
+
+use std::collections::BTreeMap;
+
+use collections2::{FixedBitSet, IterableExt};
+
+#[test]
+fn test_chunks_and_windows() {
+    let mut set = FixedBitSet::<1>::new();
+    for value in 1..=5 {
+        set.insert(value).unwrap();
+    }
+
+    let chunks: Vec<Vec<usize>> = set.chunks(2).map(|c| c.collect()).collect();
+    assert_eq!(chunks, [vec![1, 2], vec![3, 4], vec![5]]);
+
+    let windows: Vec<[usize; 3]> = set.windows::<3>().collect();
+    assert_eq!(windows, [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+    assert_eq!(set.windows::<6>().count(), 0);
+}
+
+#[test]
+fn test_group_by_and_dedup_by_key() {
+    let list = vec![1, 3, 2, 4, 6, 5];
+    let groups: Vec<(bool, Vec<i32>)> = IterableExt::group_by(&list, |x| *x % 2 == 0)
+        .map(|(even, group)| (even, group.copied().collect()))
+        .collect();
+    assert_eq!(
+        groups,
+        [(false, vec![1, 3]), (true, vec![2, 4, 6]), (false, vec![5])]
+    );
+
+    let deduped: Vec<&i32> = IterableExt::dedup_by_key(&list, |x| *x % 2).collect();
+    assert_eq!(deduped, [&1, &2, &5]);
+}
+
+#[test]
+fn test_maps_zip_and_extremes() {
+    let prices: BTreeMap<&str, u32> = [("apple", 3), ("kiwi", 1), ("pear", 2)].into();
+    let counts = vec![10, 20, 30];
+
+    let totals: Vec<u32> = prices
+        .zip_with(&counts, |(_, price), count| price * count)
+        .collect();
+    assert_eq!(totals, [30, 20, 60]);
+
+    assert_eq!(prices.min_by_key(|(_, price)| **price), Some((&"kiwi", &1)));
+    assert_eq!(
+        prices.max_by_key(|(_, price)| **price),
+        Some((&"apple", &3))
+    );
+}
@@ -1,8 +1,45 @@
+use collections2::ListSliceMut;
+
+/// Runs the slice operations on a list holding `1..=6`.
+fn check_slice_ops<L: ListSliceMut<i32>>(list: &mut L) {
+    list.rotate_left(2);
+    assert_eq!(list.as_mut_slice(), &[3, 4, 5, 6, 1, 2]);
+    list.rotate_right(2);
+    assert_eq!(list.as_mut_slice(), &[1, 2, 3, 4, 5, 6]);
+
+    let (left, right) = list.split_at_mut(3);
+    left.swap_with_slice(right);
+    assert_eq!(list.as_mut_slice(), &[4, 5, 6, 1, 2, 3]);
+
+    for chunk in list.chunks_mut(4) {
+        chunk[0] *= 10;
+    }
+    assert_eq!(list.as_mut_slice(), &[40, 5, 6, 1, 20, 3]);
+
+    list.copy_within(4.., 0);
+    assert_eq!(list.as_mut_slice(), &[20, 3, 6, 1, 20, 3]);
+
+    let (lower, median, upper) = list.select_nth_unstable(2);
+    assert_eq!(*median, 3);
+    assert!(lower.iter().all(|x| *x <= 3));
+    assert!(upper.iter().all(|x| *x >= 3));
+
+    let mut other = [0; 6];
+    list.swap_with_slice(&mut other);
+    assert_eq!(list.as_mut_slice(), &[0; 6]);
+}
+
+#[test]
+fn test_array_slice_ops() {
+    let mut array = [1, 2, 3, 4, 5, 6];
+    check_slice_ops(&mut array);
+}
+
 #[cfg(feature = "heapless")]
 mod heapless_tests {
     use core::fmt::Debug;
 
-    use collections2::{Collection as _, Error, List as _, ListMut};
+    use collections2::{Collection as _, Error, List as _, ListDrain, ListMut};
 
     fn new_list<T: PartialEq + Debug, const N: usize>() -> impl ListMut<T> {
         heapless::Vec::<T, N>::new()
@@ -122,13 +159,70 @@ mod heapless_tests {
         // Should get back our original string
         assert_eq!(result, Err(Error::InsertFailed(s2)));
     }
+
+    #[test]
+    fn test_splice_and_extend_are_atomic() {
+        let mut vec: heapless::Vec<i32, 5> = heapless::Vec::from_slice(&[1, 2, 3, 4]).unwrap();
+
+        assert_eq!(
+            vec.splice(1..2, [7, 8, 9]).err(),
+            Some(Error::CapacityExceeded)
+        );
+        assert_eq!(
+            ListDrain::extend_from_slice(&mut vec, &[5, 6]),
+            Err(Error::CapacityExceeded)
+        );
+        assert_eq!(vec.extend_from_within(..2), Err(Error::CapacityExceeded));
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+
+        let removed: heapless::Vec<i32, 2> = vec.splice(1..3, [7, 8, 9]).unwrap().collect();
+        assert_eq!(removed.as_slice(), &[2, 3]);
+        assert_eq!(vec.as_slice(), &[1, 7, 8, 9, 4]);
+    }
+
+    #[test]
+    fn test_drain_and_extract_if() {
+        let mut vec: heapless::Vec<i32, 8> =
+            heapless::Vec::from_slice(&[1, 2, 3, 4, 5, 6]).unwrap();
+
+        assert_eq!(ListDrain::drain(&mut vec, 4..).sum::<i32>(), 11);
+        let odd: heapless::Vec<i32, 4> = vec.extract_if(.., |x| *x % 2 == 1).collect();
+        assert_eq!(odd.as_slice(), &[1, 3]);
+        assert_eq!(vec.as_slice(), &[2, 4]);
+
+        ListDrain::extend_from_within(&mut vec, 1..).unwrap();
+        assert_eq!(vec.as_slice(), &[2, 4, 4]);
+    }
+
+    #[test]
+    fn test_splice_full_list() {
+        let mut vec: heapless::Vec<i32, 4> = heapless::Vec::from_slice(&[1, 2, 3, 4]).unwrap();
+
+        let removed: heapless::Vec<i32, 4> = vec.splice(1..4, [7]).unwrap().collect();
+        assert_eq!(removed.as_slice(), &[2, 3, 4]);
+        assert_eq!(vec.as_slice(), &[1, 7]);
+
+        let removed: heapless::Vec<i32, 4> = vec.splice(..1, [5, 6, 8]).unwrap().collect();
+        assert_eq!(removed.as_slice(), &[1]);
+        assert_eq!(vec.as_slice(), &[5, 6, 8, 7]);
+
+        assert_eq!(vec.splice(.., 0..5).err(), Some(Error::CapacityExceeded));
+        assert_eq!(vec.as_slice(), &[5, 6, 8, 7]);
+    }
+
+    #[test]
+    fn test_slice_ops() {
+        let mut vec: heapless::Vec<i32, 8> =
+            heapless::Vec::from_slice(&[1, 2, 3, 4, 5, 6]).unwrap();
+        super::check_slice_ops(&mut vec);
+    }
 }
 
 #[cfg(feature = "alloc")]
 mod vec_tests {
     use core::fmt::Debug;
 
-    use collections2::{Collection as _, List as _, ListMut};
+    use collections2::{Collection as _, List as _, ListDrain, ListMut};
     extern crate alloc;
     use alloc::vec::Vec;
 
@@ -185,4 +279,39 @@ mod vec_tests {
         assert_eq!(vec.get(0).unwrap(), "hello");
         assert_eq!(vec.get(1).unwrap(), "world");
     }
+
+    #[test]
+    fn test_vec_drain_and_splice() {
+        let mut vec: Vec<i32> = (1..=6).collect();
+
+        let drained: Vec<i32> = ListDrain::drain(&mut vec, 1..3).collect();
+        assert_eq!(drained, [2, 3]);
+        assert_eq!(vec, [1, 4, 5, 6]);
+
+        let removed: Vec<i32> = ListDrain::splice(&mut vec, ..=1, [9, 9, 9])
+            .unwrap()
+            .collect();
+        assert_eq!(removed, [1, 4]);
+        assert_eq!(vec, [9, 9, 9, 5, 6]);
+    }
+
+    #[test]
+    fn test_vec_extract_if_and_extend() {
+        let mut vec: Vec<i32> = (1..=6).collect();
+
+        let mut extracted = vec.extract_if(1..5, |x| *x % 2 == 0);
+        assert_eq!(extracted.next(), Some(2));
+        drop(extracted);
+        assert_eq!(vec, [1, 3, 4, 5, 6]);
+
+        ListDrain::extend_from_slice(&mut vec, &[7]).unwrap();
+        ListDrain::extend_from_within(&mut vec, 4..).unwrap();
+        assert_eq!(vec, [1, 3, 4, 5, 6, 7, 6, 7]);
+    }
+
+    #[test]
+    fn test_vec_slice_ops() {
+        let mut vec: Vec<i32> = (1..=6).collect();
+        super::check_slice_ops(&mut vec);
+    }
 }
@@ -0,0 +1,107 @@
+use collections2::{ArrayLruMap, Map, MapMut};
+
+#[test]
+fn test_touch_updates_recency() {
+    let mut cache = ArrayLruMap::<u8, &str, 2>::new();
+    cache.insert(1, "one");
+    cache.insert(2, "two");
+
+    assert_eq!(cache.touch(&1), Some(&"one"));
+    assert_eq!(cache.push(3, "three"), Some((2, "two")));
+    assert_eq!(cache.keys().copied().collect::<Vec<_>>(), vec![3, 1]);
+}
+
+/// Without `Map` in scope, `cache.get` is the inherent, touching one.
+mod inherent_get {
+    use collections2::ArrayLruMap;
+
+    #[test]
+    fn test_get_updates_recency() {
+        let mut cache = ArrayLruMap::<u8, &str, 2>::new();
+        cache.push(1, "one");
+        cache.push(2, "two");
+
+        assert_eq!(cache.get(&1), Some(&"one"));
+        assert_eq!(cache.push(3, "three"), Some((2, "two")));
+        assert_eq!(cache.get(&2), None);
+    }
+}
+
+#[test]
+fn test_get_keeps_recency() {
+    let mut cache = ArrayLruMap::<u8, &str, 2>::new();
+    cache.insert(1, "one");
+    cache.insert(2, "two");
+
+    let order: Vec<_> = cache.keys().filter_map(|k| cache.get(k)).collect();
+    assert_eq!(order, vec![&"two", &"one"]);
+    assert_eq!(cache.push(3, "three"), Some((1, "one")));
+}
+
+#[test]
+fn test_clone_and_debug() {
+    let mut cache = ArrayLruMap::<u8, u32, 3>::new();
+    cache.insert(1, 10);
+    cache.insert(2, 20);
+
+    let mut copy = cache.clone();
+    copy.touch(&1);
+    assert_eq!(format!("{:?}", cache), "{2: 20, 1: 10}");
+    assert_eq!(format!("{:?}", copy), "{1: 10, 2: 20}");
+}
+
+#[test]
+fn test_peek_keeps_recency() {
+    let mut cache = ArrayLruMap::<u8, &str, 2>::new();
+    cache.insert(1, "one");
+    cache.insert(2, "two");
+
+    assert_eq!(cache.peek(&1), Some(&"one"));
+    assert_eq!(cache.peek_lru(), Some((&1, &"one")));
+    assert_eq!(cache.push(3, "three"), Some((1, "one")));
+    assert!(!cache.contains_key(&1));
+}
+
+#[test]
+fn test_replace_and_remove() {
+    let mut cache = ArrayLruMap::<u8, u32, 3>::new();
+    cache.insert(1, 10);
+    cache.insert(2, 20);
+
+    assert_eq!(cache.insert(1, 11), Some(10));
+    assert_eq!(cache.remove(&2), Some(20));
+    assert_eq!(cache.pop_lru(), Some((1, 11)));
+    assert_eq!(cache.len(), 0);
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_tests {
+    use core::cell::RefCell;
+
+    use collections2::{GetMut, Iterable, LruMap, Map, MapMut};
+
+    #[test]
+    fn test_eviction_hook() {
+        let evicted = RefCell::new(Vec::new());
+        let mut cache = LruMap::with_capacity_and_hook(2, |k: &u32, v: &u32| {
+            evicted.borrow_mut().push((*k, *v))
+        });
+        for i in 0..5 {
+            cache.insert(i, i * 10);
+        }
+        if let Some(v) = cache.get_mut(&3) {
+            *v += 1;
+        }
+        cache.insert(5, 50);
+
+        assert_eq!(*evicted.borrow(), vec![(0, 0), (1, 10), (2, 20), (4, 40)]);
+        assert_eq!(cache.iter().collect::<Vec<_>>(), vec![(&5, &50), (&3, &31)]);
+    }
+
+    #[test]
+    fn test_zero_capacity_stores_nothing() {
+        let mut cache = LruMap::with_capacity(0);
+        assert_eq!(cache.push("a", 1), Some(("a", 1)));
+        assert_eq!(cache.get(&"a"), None);
+    }
+}
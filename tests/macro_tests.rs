@@ -0,0 +1,92 @@
+use collections2::{list, set, try_set, Collection, Error, FixedBitSet, Iterable, List, WordList};
+
+#[test]
+fn test_macros_without_alloc() {
+    let bits = list![WordList<[u64; 1]>; true, false, true];
+    assert_eq!(bits.len(), 3);
+    assert_eq!(bits.get(1), Some(&false));
+
+    let empty = list![WordList<[u64; 1]>];
+    assert!(empty.is_empty());
+
+    let set = set![FixedBitSet<1>; 3, 1, 4, 1];
+    assert_eq!(set.iter().collect::<Vec<_>>(), [1, 3, 4]);
+    assert_eq!(
+        try_set![FixedBitSet<1>; 1, 64],
+        Err(Error::InsertFailed(64))
+    );
+}
+
+#[test]
+#[should_panic(expected = "set! ran out of capacity")]
+fn test_set_macro_panics_when_full() {
+    let _ = set![FixedBitSet<1>; 100];
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_tests {
+    use std::collections::BTreeMap;
+
+    use collections2::{map, try_list, try_map};
+
+    use super::*;
+
+    #[test]
+    fn test_list_macro() {
+        let list = list![Vec<u32>; 1, 2, 3,];
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(try_list![Vec<u32>; 4, 5], Ok(vec![4, 5]));
+        assert_eq!(list![Vec<u32>;], Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_map_macro() {
+        let map = map![BTreeMap<&str, u32>; "a" => 1, "b" => 2, "a" => 3];
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["a"], 3);
+        assert_eq!(try_map![BTreeMap<u8, u8>], Ok(BTreeMap::new()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_set_macro() {
+        let set = set![std::collections::HashSet<char>; 'x', 'y', 'x'];
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&'y'));
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+    use collections2::{try_list, try_map, FlatMap, Map};
+
+    use super::*;
+
+    #[test]
+    fn test_try_list_overflow() {
+        assert_eq!(
+            try_list![heapless::Vec<u8, 2>; 1, 2, 3],
+            Err(Error::InsertFailed(3))
+        );
+        let list = try_list![heapless::Vec<u8, 2>; 1, 2].unwrap();
+        assert_eq!(list.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_try_map_overflow() {
+        type Small = FlatMap<u8, char, heapless::Vec<(u8, char), 2>>;
+
+        let map = try_map![Small; 2 => 'b', 1 => 'a', 2 => 'c'].unwrap();
+        assert_eq!(map.get(&2), Some(&'c'));
+        assert!(matches!(
+            try_map![Small; 1 => 'a', 2 => 'b', 3 => 'c'],
+            Err(Error::InsertFailed((3, 'c')))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "list! ran out of capacity")]
+    fn test_list_macro_panics_when_full() {
+        let _ = list![heapless::Vec<u8, 1>; 1, 2];
+    }
+}
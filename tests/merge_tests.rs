@@ -0,0 +1,114 @@
+#![cfg(feature = "alloc")]
+
+use collections2::{
+    kmerge, kmerge_by, merge_inplace, merge_inplace_by, merge_sorted_into, merge_sorted_into_by,
+    Bounded, Error, List,
+};
+
+#[test]
+fn test_merge_sorted_into() {
+    let a = vec![1, 3, 5, 7];
+    let b = vec![2, 3, 4, 8, 9];
+    let mut out = vec![0];
+    merge_sorted_into(&a, &b, &mut out).unwrap();
+    assert_eq!(out, [0, 1, 2, 3, 3, 4, 5, 7, 8, 9]);
+
+    let mut out = Vec::new();
+    merge_sorted_into(&Vec::<u32>::new(), &b, &mut out).unwrap();
+    assert_eq!(out, b);
+}
+
+#[test]
+fn test_merge_sorted_into_is_stable() {
+    let a = vec![(1, 'a'), (2, 'a'), (2, 'b')];
+    let b = vec![(1, 'x'), (2, 'x'), (3, 'x')];
+    let mut out = Vec::new();
+    merge_sorted_into_by(&a, &b, &mut out, |x, y| x.0.cmp(&y.0)).unwrap();
+    assert_eq!(
+        out,
+        [(1, 'a'), (1, 'x'), (2, 'a'), (2, 'b'), (2, 'x'), (3, 'x')]
+    );
+}
+
+#[test]
+fn test_merge_sorted_into_bounded() {
+    let a = vec![1, 4, 6];
+    let b = vec![2, 3, 5];
+    let mut out = Bounded::with_limit(Vec::new(), 4);
+    assert_eq!(
+        merge_sorted_into(&a, &b, &mut out),
+        Err(Error::InsertFailed(5))
+    );
+    assert_eq!(out.as_slice(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn test_merge_inplace() {
+    let mut list = vec![1, 4, 6, 9, 2, 3, 5, 7, 8, 10];
+    merge_inplace(&mut list, 4);
+    assert_eq!(list, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+    for mid in [0, 3] {
+        let mut list = vec![1, 2, 3];
+        merge_inplace(&mut list, mid);
+        assert_eq!(list, [1, 2, 3]);
+    }
+}
+
+#[test]
+fn test_merge_inplace_is_stable() {
+    // Every split of a list of tagged keys, checked against a stable sort.
+    let keys = [3, 1, 2, 1, 3, 0, 2, 2, 1, 3, 0, 1];
+    let tagged: Vec<(u32, usize)> = keys.iter().copied().zip(0..).collect();
+    for mid in 0..=tagged.len() {
+        let mut list = tagged.clone();
+        list[..mid].sort_by_key(|x| x.0);
+        list[mid..].sort_by_key(|x| x.0);
+        let mut expected = list.clone();
+        expected.sort_by_key(|x| x.0);
+
+        merge_inplace_by(&mut list, mid, |x, y| x.0.cmp(&y.0));
+        assert_eq!(list, expected, "split at {mid}");
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_merge_inplace_out_of_bounds() {
+    merge_inplace(&mut vec![1, 2], 3);
+}
+
+#[test]
+fn test_kmerge() {
+    let lists = [vec![1, 5, 9], vec![], vec![2, 3, 10], vec![4]];
+    let merged: Vec<_> = kmerge(&lists).copied().collect();
+    assert_eq!(merged, [1, 2, 3, 4, 5, 9, 10]);
+    assert_eq!(kmerge(&lists).size_hint(), (7, Some(7)));
+    assert_eq!(kmerge(&[] as &[Vec<u32>]).next(), None);
+}
+
+#[test]
+fn test_kmerge_by_is_stable() {
+    let lists = [
+        vec![(1, 'a'), (2, 'a'), (2, 'b')],
+        vec![(0, 'x'), (2, 'x')],
+        vec![(1, 'y'), (2, 'y')],
+    ];
+    let merged: Vec<_> = kmerge_by(&lists, |x, y| x.0.cmp(&y.0)).copied().collect();
+    assert_eq!(
+        merged,
+        [
+            (0, 'x'),
+            (1, 'a'),
+            (1, 'y'),
+            (2, 'a'),
+            (2, 'b'),
+            (2, 'x'),
+            (2, 'y'),
+        ]
+    );
+
+    let descending = [vec![9, 3], vec![8, 7, 1]];
+    let merged: Vec<_> = kmerge_by(&descending, |x, y| y.cmp(x)).copied().collect();
+    assert_eq!(merged, [9, 8, 7, 3, 1]);
+}
@@ -0,0 +1,80 @@
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use collections2::{MultiMap, MultiMapMut};
+
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+fn tag<M: MultiMapMut<&'static str, u32>>(map: &mut M) {
+    map.insert("red", 1).unwrap();
+    map.insert("red", 2).unwrap();
+    map.insert("blue", 3).unwrap();
+}
+
+#[cfg(feature = "std")]
+mod hashmap_tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_and_get_all() {
+        let mut map = HashMap::<&str, Vec<u32>>::new();
+        tag(&mut map);
+
+        assert_eq!(map.get_all(&"red").copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(map.get_all(&"green").count(), 0);
+        assert_eq!(map.key_count(), 2);
+        assert_eq!(map.total_len(), 3);
+    }
+
+    #[test]
+    fn test_remove_one_drops_empty_keys() {
+        let mut map = HashMap::<&str, Vec<u32>>::new();
+        tag(&mut map);
+
+        assert!(map.remove_one(&"blue", &3));
+        assert!(!map.remove_one(&"blue", &3));
+        assert!(!map.contains_key(&"blue"));
+        assert_eq!(map.remove_all(&"red"), 2);
+        assert_eq!(map.total_len(), 0);
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod btreemap_tests {
+    extern crate alloc;
+    use alloc::collections::{BTreeMap, BTreeSet};
+
+    use super::*;
+
+    #[test]
+    fn test_set_values_are_deduplicated() {
+        let mut map = BTreeMap::<&str, BTreeSet<u32>>::new();
+        tag(&mut map);
+
+        assert_eq!(MultiMapMut::insert(&mut map, "red", 1), Ok(false));
+        assert_eq!(map.total_len(), 3);
+        assert!(map.remove_one(&"red", &1));
+        assert_eq!(map.get_all(&"red").copied().collect::<Vec<_>>(), vec![2]);
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+    use collections2::{Error, FixedMultiMap};
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_capacity() {
+        let mut map = FixedMultiMap::<&str, u32, 2, 2>::new();
+        tag(&mut map);
+
+        assert_eq!(map.insert("red", 9), Err(Error::InsertFailed(("red", 9))));
+        assert_eq!(
+            map.insert("green", 4),
+            Err(Error::InsertFailed(("green", 4)))
+        );
+        assert_eq!(map.total_len(), 3);
+        assert_eq!(map.remove_all(&"red"), 2);
+        assert_eq!(map.insert("green", 4), Ok(true));
+    }
+}
@@ -0,0 +1,196 @@
+#![cfg(feature = "std")]
+
+use std::collections::{BTreeMap, HashSet};
+
+use collections2::{
+    Change, CollectionMut, List, ListMut, ListSortable, MapMut, Observed, SetMut, Splice,
+};
+
+#[test]
+fn test_list_changes_are_buffered() {
+    let mut list = Observed::buffered(vec![3, 1, 2]);
+
+    list.push(4).unwrap();
+    list.insert(0, 0).unwrap();
+    assert_eq!(list.remove(1), 3);
+    list.set(0, 9).unwrap();
+    list.sort();
+
+    assert_eq!(
+        list.take_changes(),
+        [
+            Change::Inserted { index: 3 },
+            Change::Inserted { index: 0 },
+            Change::Removed { index: 1, value: 3 },
+            Change::Replaced { index: 0, value: 0 },
+            Change::Sorted,
+        ]
+    );
+    assert!(list.changes().is_empty());
+    assert_eq!(list.as_slice(), &[1, 2, 4, 9][..]);
+}
+
+#[test]
+fn test_bulk_removals_are_reported_in_order() {
+    let mut list = Observed::buffered(vec![1, 1, 2, 3, 3, 4, 5]);
+    list.dedup();
+    list.retain(|x| x % 2 == 1);
+    list.truncate(1);
+
+    assert_eq!(
+        list.take_changes(),
+        [
+            Change::Removed { index: 1, value: 1 },
+            Change::Removed { index: 3, value: 3 },
+            Change::Removed { index: 1, value: 2 },
+            Change::Removed { index: 2, value: 4 },
+            Change::Removed { index: 2, value: 5 },
+            Change::Removed { index: 1, value: 3 },
+        ]
+    );
+    assert_eq!(list.as_slice(), &[1][..]);
+}
+
+#[test]
+fn test_callback_sees_changes_as_they_happen() {
+    let mut seen = Vec::new();
+    let mut list = Observed::with_callback(vec![1, 2, 3], |change| seen.push(change));
+    *list.get_mut(1).unwrap() = 20;
+    list.swap_remove(0);
+    list.clear();
+    drop(list);
+
+    assert_eq!(
+        seen,
+        [
+            Change::Replaced { index: 1, value: 2 },
+            Change::Removed { index: 2, value: 3 },
+            Change::Replaced { index: 0, value: 1 },
+            Change::Cleared,
+        ]
+    );
+}
+
+#[test]
+fn test_coalesced_splices_rebuild_the_list() {
+    let before = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let mut list = Observed::coalesced(before.clone());
+
+    list.remove(2);
+    list.insert(2, 20).unwrap();
+    list.insert(3, 30).unwrap();
+    list.set(7, 70).unwrap();
+    list.push(10).unwrap();
+    list.pop();
+    list.pop();
+
+    let splices = list.take_splices();
+    assert_eq!(
+        splices,
+        [
+            Splice {
+                index: 2,
+                removed: 1,
+                inserted: 2
+            },
+            Splice {
+                index: 7,
+                removed: 1,
+                inserted: 1
+            },
+            Splice {
+                index: 10,
+                removed: 1,
+                inserted: 0
+            },
+        ]
+    );
+
+    let mut rebuilt = before;
+    for splice in splices {
+        let range = splice.index..splice.index + splice.removed;
+        let fresh = &list.as_slice()[splice.index..splice.index + splice.inserted];
+        rebuilt.splice(range, fresh.iter().copied());
+    }
+    assert_eq!(rebuilt, list.as_slice());
+}
+
+#[test]
+fn test_coalesced_clear_and_sort_cover_everything() {
+    let mut list = Observed::coalesced(vec![3, 2, 1]);
+    list.push(0).unwrap();
+    list.sort();
+    assert_eq!(
+        list.take_splices(),
+        [Splice {
+            index: 0,
+            removed: 3,
+            inserted: 4
+        }]
+    );
+
+    list.clear();
+    assert_eq!(
+        list.take_splices(),
+        [Splice {
+            index: 0,
+            removed: 4,
+            inserted: 0
+        }]
+    );
+}
+
+#[test]
+fn test_map_and_set_changes() {
+    let mut map = Observed::buffered(BTreeMap::new());
+    map.insert("a", 1);
+    map.insert("a", 2);
+    map.remove(&"a");
+    map.remove(&"missing");
+    assert_eq!(
+        map.take_changes(),
+        [
+            Change::Inserted { index: "a" },
+            Change::Replaced {
+                index: "a",
+                value: 1
+            },
+            Change::Removed {
+                index: "a",
+                value: 2
+            },
+        ]
+    );
+
+    let mut set = Observed::buffered(HashSet::new());
+    assert!(set.insert(1));
+    assert!(!set.insert(1));
+    assert!(set.remove(&1));
+    assert_eq!(
+        set.take_changes(),
+        [
+            Change::Inserted { index: 1 },
+            Change::Removed {
+                index: 1,
+                value: ()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_split_off_tail_is_unobserved() {
+    let mut list = Observed::buffered(vec![1, 2, 3]);
+    let mut tail = list.split_off(1);
+    tail.push(4).unwrap();
+
+    assert!(tail.observer().is_none());
+    assert_eq!(tail.as_slice(), &[2, 3, 4][..]);
+    assert_eq!(
+        list.take_changes(),
+        [
+            Change::Removed { index: 2, value: 3 },
+            Change::Removed { index: 1, value: 2 },
+        ]
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "alloc")]
+
+use collections2::{
+    FnvBuildHasher, Iterable, Map, MapMut, OrderedHashMap, OrderedHashSet, Set, SetMut,
+};
+
+#[cfg(feature = "std")]
+#[test]
+fn test_iteration_follows_insertion_order() {
+    let mut map = OrderedHashMap::new();
+    for key in ["zeta", "alpha", "mu", "beta"] {
+        map.insert(key, key.len());
+    }
+    map.insert("alpha", 0);
+
+    assert_eq!(
+        map.keys().copied().collect::<Vec<_>>(),
+        ["zeta", "alpha", "mu", "beta"]
+    );
+    assert_eq!(map.get_index(1), Some((&"alpha", &0)));
+    assert_eq!(map.get_index_of(&"mu"), Some(2));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_swap_and_shift_remove() {
+    let mut map = OrderedHashMap::new();
+    for i in 0..5 {
+        map.insert(i, i * 10);
+    }
+
+    assert_eq!(map.swap_remove_index(1), Some((1, 10)));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 4, 2, 3]);
+    assert_eq!(map.shift_remove(&4), Some(40));
+    assert_eq!(MapMut::remove(&mut map, &0), Some(0));
+    assert_eq!(map.iter().collect::<Vec<_>>(), [(&2, &20), (&3, &30)]);
+    assert_eq!(map.get(&3), Some(&30));
+    assert_eq!(map.get(&4), None);
+}
+
+#[test]
+fn test_lookups_survive_many_removals() {
+    let mut map = OrderedHashMap::with_hasher(FnvBuildHasher::default());
+    for i in 0..1000u32 {
+        map.insert(i, i);
+    }
+    for i in (0..1000).step_by(3) {
+        assert_eq!(map.swap_remove(&i), Some(i));
+    }
+    for i in 0..1000 {
+        assert_eq!(map.get(&i).is_some(), i % 3 != 0, "key {i}");
+    }
+    assert_eq!(map.len(), 666);
+}
+
+#[test]
+fn test_set_keeps_order() {
+    let mut set = OrderedHashSet::with_hasher(FnvBuildHasher::default());
+    assert!(set.insert('c'));
+    assert!(set.insert('a'));
+    assert!(!set.insert('c'));
+    assert!(set.insert('b'));
+    assert!(set.remove(&'a'));
+
+    assert_eq!(set.iter().copied().collect::<String>(), "cb");
+    assert_eq!(set.get_index(1), Some(&'b'));
+    assert_eq!(Set::get(&set, &'c'), Some(&'c'));
+}
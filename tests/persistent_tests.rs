@@ -0,0 +1,108 @@
+#![cfg(feature = "alloc")]
+
+use collections2::{FnvBuildHasher, GetMut, List, ListMut, Map, MapMut, PList, PMap};
+
+#[test]
+fn test_list_snapshots_are_independent() {
+    let mut list: PList<u32> = (0..1000).collect();
+    let snapshot = list.clone();
+    assert!(list.ptr_eq(&snapshot));
+
+    list.set(500, 0).unwrap();
+    list.push(1000).unwrap();
+    ListMut::remove(&mut list, 0);
+
+    assert!(!list.ptr_eq(&snapshot));
+    assert_eq!(snapshot.len(), 1000);
+    assert_eq!(snapshot.get(500), Some(&500));
+    assert_eq!(snapshot.first(), Some(&0));
+    assert_eq!(list.len(), 1000);
+    assert_eq!(list.get(499), Some(&0));
+    assert_eq!(list.last(), Some(&1000));
+}
+
+#[test]
+fn test_list_matches_vec() {
+    let mut list = PList::new();
+    let mut vec = Vec::new();
+    for i in 0..500u32 {
+        let index = (i as usize * 7) % (vec.len() + 1);
+        list.insert(index, i).unwrap();
+        vec.insert(index, i);
+    }
+    assert_eq!(list.as_slice(), vec.as_slice());
+
+    for i in 0..400 {
+        let index = (i * 13) % vec.len();
+        assert_eq!(ListMut::remove(&mut list, index), vec.remove(index));
+    }
+    assert_eq!(list.as_slice(), vec.as_slice());
+
+    list.retain(|x| x % 2 == 0);
+    vec.retain(|x| x % 2 == 0);
+    let tail = list.split_off(10);
+    assert_eq!(tail.len(), vec.len() - 10);
+    vec.truncate(10);
+    assert_eq!(list.as_slice(), vec.as_slice());
+}
+
+#[test]
+fn test_list_binary_search() {
+    let list: PList<u32> = (0..100).map(|x| x * 2).collect();
+    assert_eq!(list.binary_search(&40), Ok(20));
+    assert_eq!(list.binary_search(&41), Err(21));
+    assert!(list.starts_with(&[0, 2, 4]));
+    assert!(list.ends_with(&[196, 198]));
+}
+
+#[test]
+fn test_map_snapshots_are_independent() {
+    let mut map = PMap::with_hasher(FnvBuildHasher::default());
+    for i in 0..1000u32 {
+        map.insert(i, i * 10);
+    }
+    let snapshot = map.clone();
+
+    *GetMut::get_mut(&mut map, &7).unwrap() = 0;
+    map.remove(&8);
+    map.insert(1000, 1);
+
+    assert_eq!(snapshot.len(), 1000);
+    assert_eq!(snapshot.get(&7), Some(&70));
+    assert_eq!(snapshot.get(&8), Some(&80));
+    assert_eq!(snapshot.get(&1000), None);
+    assert_eq!(map.len(), 1000);
+    assert_eq!(map.get(&7), Some(&0));
+    assert_eq!(map.get(&8), None);
+}
+
+#[test]
+fn test_map_traits() {
+    let mut map: PMap<&str, u32, FnvBuildHasher> =
+        [("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+    let snapshot = map.clone();
+    for value in map.values_mut() {
+        *value *= 10;
+    }
+
+    assert_eq!(Map::get(&map, &"b"), Some(&20));
+    assert_eq!(snapshot.get(&"b"), Some(&2));
+    assert_eq!(map.values().sum::<u32>(), 60);
+    assert_eq!(map.keys().count(), 3);
+    assert_ne!(map, snapshot);
+
+    for key in ["a", "b", "c"] {
+        assert!(map.remove(&key).is_some());
+    }
+    assert!(map.is_empty());
+    assert_eq!(map.iter().count(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_map_default_hasher() {
+    let mut map = PMap::new();
+    map.insert("a", 1);
+    let collected: PMap<&str, u32> = map.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(collected, map);
+}
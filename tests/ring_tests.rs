@@ -0,0 +1,103 @@
+use collections2::{Collection, CollectionMut, Error, Iterable, List, RingBuffer};
+
+#[test]
+fn test_push_overwrite_evicts_oldest() {
+    let mut ring = RingBuffer::<u32, 3>::new();
+    for i in 0..3 {
+        assert_eq!(ring.push_overwrite(i), None);
+    }
+    assert_eq!(ring.push(3), Err(Error::InsertFailed(3)));
+    assert_eq!(ring.push_overwrite(3), Some(0));
+    assert_eq!(ring.push_overwrite(4), Some(1));
+
+    assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(List::get(&ring, 0), Some(&2));
+    assert_eq!(ring.as_slice(), &[2, 3, 4][..]);
+    assert_eq!(ring.as_slices(), (&[2][..], &[3, 4][..]));
+    assert_eq!(ring.make_contiguous(), &[2, 3, 4]);
+    assert_eq!(ring.as_slices(), (&[2, 3, 4][..], &[][..]));
+    assert_eq!(ring.push_overwrite(5), Some(2));
+    assert_eq!(ring.as_slices(), (&[3, 4][..], &[5][..]));
+}
+
+#[test]
+fn test_search_across_wrap() {
+    let mut ring = RingBuffer::<u32, 5>::new();
+    for i in 0..8 {
+        ring.push_overwrite(i * 10);
+    }
+    assert_eq!(ring.as_slices(), (&[30, 40][..], &[50, 60, 70][..]));
+
+    for (i, x) in [30, 40, 50, 60, 70].iter().enumerate() {
+        assert_eq!(ring.binary_search(x), Ok(i));
+    }
+    assert_eq!(ring.binary_search(&0), Err(0));
+    assert_eq!(ring.binary_search(&45), Err(2));
+    assert_eq!(ring.binary_search(&99), Err(5));
+    assert_eq!(ring.find_index(&60), Some(3));
+    assert!(ring.starts_with(&[30, 40, 50]));
+    assert!(ring.ends_with(&[50, 60, 70]));
+    assert_eq!(ring.as_slice().get(2), Some(&50));
+}
+
+#[test]
+fn test_stays_ordered_across_wrap() {
+    let mut ring = RingBuffer::<usize, 4>::new();
+    for i in 0..100 {
+        ring.push_overwrite(i);
+        let expected: Vec<_> = (i.saturating_sub(3)..=i).collect();
+        assert_eq!(ring.as_slice(), expected.as_slice());
+    }
+    assert_eq!(ring.pop_oldest(), Some(96));
+    assert_eq!(ring.pop_newest(), Some(99));
+    assert!(ring.contains(&97));
+}
+
+#[test]
+fn test_drops_live_elements() {
+    use std::rc::Rc;
+
+    let marker = Rc::new(());
+    let mut ring = RingBuffer::<Rc<()>, 2>::new();
+    for _ in 0..5 {
+        ring.push_overwrite(marker.clone());
+    }
+    assert_eq!(Rc::strong_count(&marker), 3);
+    ring.clear();
+    assert_eq!(Rc::strong_count(&marker), 1);
+    ring.push_overwrite(marker.clone());
+    drop(ring);
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_heap_ring_buffer() {
+    use collections2::HeapRingBuffer;
+
+    let mut ring = HeapRingBuffer::with_capacity(2);
+    ring.push_overwrite("a");
+    ring.push_overwrite("b");
+    assert_eq!(ring.push_overwrite("c"), Some("a"));
+    assert_eq!(ring.capacity(), 2);
+    assert_eq!(ring.first(), Some(&"b"));
+    assert_eq!(ring.last(), Some(&"c"));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_history_buf() {
+    let mut history = heapless::HistoryBuf::<u8, 3>::new();
+    history.extend_from_slice(&[1, 2, 3, 4]);
+
+    assert_eq!(history.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(Collection::len(&history), 3);
+    assert!(history.contains(&4));
+
+    assert_eq!(List::as_slice(&history), &[2, 3, 4][..]);
+    assert_eq!(List::get(&history, 0), Some(&2));
+    assert_eq!(List::first(&history), Some(&2));
+    assert_eq!(List::last(&history), Some(&4));
+    assert_eq!(List::binary_search(&history, &3), Ok(1));
+    assert!(List::ends_with(&history, &[3, 4]));
+}
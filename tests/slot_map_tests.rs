@@ -0,0 +1,58 @@
+use collections2::{ArraySlotMap, Error, Map, MapMut};
+
+#[test]
+fn test_removed_key_goes_stale() {
+    let mut map = ArraySlotMap::<&str, 4>::new();
+    let a = map.insert_value("a").unwrap();
+    let b = map.insert_value("b").unwrap();
+
+    assert_eq!(map.remove(a), Some("a"));
+    assert_eq!(map.get(a), None);
+    assert_eq!(map.get(b), Some(&"b"));
+
+    let c = map.insert_value("c").unwrap();
+    assert_eq!(c.index(), a.index());
+    assert_ne!(c, a);
+    assert_eq!(map.get(a), None);
+    assert_eq!(map.get(c), Some(&"c"));
+}
+
+#[test]
+fn test_fixed_capacity() {
+    let mut map = ArraySlotMap::<u8, 2>::new();
+    let a = map.insert_value(1).unwrap();
+    map.insert_value(2).unwrap();
+
+    assert_eq!(map.insert_value(3), Err(Error::InsertFailed(3)));
+    map.remove(a);
+    assert!(map.insert_value(3).is_ok());
+}
+
+#[test]
+fn test_map_traits() {
+    let mut map = ArraySlotMap::<u32, 4>::new();
+    let a = map.insert_value(10).unwrap();
+    let b = map.insert_value(20).unwrap();
+    map.remove(a);
+
+    assert_eq!(map.insert(b, 21), Some(20));
+    assert_eq!(map.try_insert(a, 11), Err(Error::InsertFailed((a, 11))));
+    assert_eq!(Map::len(&map), 1);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [b]);
+    assert_eq!(Map::get(&map, &b), Some(&21));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_slot_map_grows() {
+    use collections2::SlotMap;
+
+    let mut map = SlotMap::new();
+    let keys: Vec<_> = (0..100).map(|i| map.insert_value(i)).collect();
+    for key in keys.iter().step_by(2) {
+        map.remove(*key);
+    }
+    map.clear();
+    assert!(map.is_empty());
+    assert!(keys.iter().all(|key| !map.contains_key(*key)));
+}
@@ -0,0 +1,76 @@
+#[cfg(feature = "alloc")]
+mod vec_tests {
+    use collections2::{Collection as _, List as _, SetMut, SortedList, SortedSet};
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_insert_keeps_order() {
+        let mut list = SortedList::<Vec<u32>>::new();
+
+        for x in [5, 1, 4, 1, 3] {
+            list.insert_sorted(x).unwrap();
+        }
+
+        assert_eq!(list.as_slice(), &[1, 1, 3, 4, 5]);
+        assert!(list.contains(&4));
+        assert_eq!(list.find_index(&3), Some(2));
+        assert_eq!(list.find_index(&2), None);
+    }
+
+    #[test]
+    fn test_range_rank_select() {
+        let list = SortedList::<Vec<u32>>::from_unsorted(vec![9, 3, 7, 1, 5]);
+
+        assert_eq!(list.range(3..7).copied().collect::<Vec<_>>(), vec![3, 5]);
+        assert_eq!(list.range(3..=7).copied().collect::<Vec<_>>(), vec![3, 5, 7]);
+        assert_eq!(list.range(..).count(), 5);
+        assert_eq!(list.rank(&6), 3);
+        assert_eq!(list.select(3), Some(&7));
+    }
+
+    #[test]
+    fn test_remove_value() {
+        let mut list = SortedList::<Vec<u32>>::from_unsorted(vec![2, 1, 2]);
+
+        assert_eq!(list.remove_value(&2), Some(2));
+        assert_eq!(list.remove_value(&3), None);
+        assert_eq!(list.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_sorted_set_rejects_duplicates() {
+        let mut set = SortedSet::<Vec<u32>>::new();
+
+        assert!(set.insert(3));
+        assert!(set.insert(1));
+        assert!(!set.insert(3));
+        assert!(set.remove(&1));
+        assert_eq!(set.as_slice(), &[3]);
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+    use collections2::{Error, List as _, SetMut, SortedList, SortedSet};
+
+    #[test]
+    fn test_insert_at_capacity() {
+        let mut list = SortedList::<heapless::Vec<u32, 2>>::new();
+
+        list.insert_sorted(2).unwrap();
+        list.insert_sorted(1).unwrap();
+
+        assert_eq!(list.insert_sorted(0), Err(Error::InsertFailed(0)));
+        assert_eq!(list.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_sorted_set_try_insert_at_capacity() {
+        let mut set = SortedSet::<heapless::Vec<u32, 1>>::new();
+
+        assert_eq!(set.try_insert(1), Ok(true));
+        assert_eq!(set.try_insert(1), Ok(false));
+        assert_eq!(set.try_insert(2), Err(Error::InsertFailed(2)));
+    }
+}
@@ -0,0 +1,101 @@
+use collections2::{ArrayTrieMap, Error, MapMut};
+
+#[test]
+fn test_fixed_pool() {
+    let mut trie = ArrayTrieMap::<&str, u8, 5>::new();
+    assert_eq!(trie.try_insert("abc", 1), Ok(None));
+    assert_eq!(trie.try_insert("abd", 2), Ok(None));
+    assert_eq!(trie.node_count(), 4);
+
+    // Failing midway leaves no half-built path behind.
+    assert_eq!(
+        trie.try_insert("xy", 3),
+        Err(Error::InsertFailed(("xy", 3)))
+    );
+    assert_eq!(trie.node_count(), 4);
+    assert_eq!(trie.try_insert("ab", 4), Ok(None));
+
+    trie.remove("abc");
+    assert_eq!(trie.try_insert("x", 5), Ok(None));
+    assert_eq!(trie.len(), 3);
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_tests {
+    use collections2::{Iterable, Map, MapMut, TrieMap};
+
+    #[test]
+    fn test_get_insert_remove() {
+        let mut trie = TrieMap::new();
+        assert_eq!(trie.insert("tea", 1), None);
+        assert_eq!(trie.insert("ten", 2), None);
+        assert_eq!(trie.insert("", 0), None);
+        assert_eq!(trie.insert("tea", 3), Some(1));
+
+        assert_eq!(trie.len(), 3);
+        assert_eq!(trie.get("tea"), Some(&3));
+        assert_eq!(trie.get("te"), None);
+        assert_eq!(trie.get(""), Some(&0));
+
+        assert_eq!(trie.remove("tea"), Some(3));
+        assert_eq!(trie.remove("tea"), None);
+        assert_eq!(trie.len(), 2);
+        // "ten" still holds "t", "e" and "n".
+        assert_eq!(trie.node_count(), 3);
+        assert_eq!(trie.remove("ten"), Some(2));
+        assert_eq!(trie.node_count(), 0);
+    }
+
+    #[test]
+    fn test_lexicographic_iteration() {
+        let mut trie = TrieMap::new();
+        for (i, key) in ["to", "b", "tea", "a", "t", "ted"].iter().enumerate() {
+            trie.insert(*key, i);
+        }
+
+        let keys: Vec<_> = trie.keys().copied().collect();
+        assert_eq!(keys, ["a", "b", "t", "tea", "ted", "to"]);
+
+        let prefixed: Vec<_> = trie.keys_with_prefix("te").copied().collect();
+        assert_eq!(prefixed, ["tea", "ted"]);
+        let pairs: Vec<_> = trie.iter_prefix("t").map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(pairs, [("t", 4), ("tea", 2), ("ted", 5), ("to", 0)]);
+        assert_eq!(trie.iter_prefix("x").count(), 0);
+        assert_eq!(trie.iter().count(), 6);
+    }
+
+    #[test]
+    fn test_longest_prefix() {
+        let mut trie = TrieMap::new();
+        trie.insert(String::from("/"), "root");
+        trie.insert(String::from("/api"), "api");
+        trie.insert(String::from("/api/v2"), "v2");
+
+        assert_eq!(
+            trie.longest_prefix("/api/v1/users").map(|(_, v)| *v),
+            Some("api")
+        );
+        assert_eq!(
+            trie.longest_prefix("/api/v2/users").map(|(_, v)| *v),
+            Some("v2")
+        );
+        assert_eq!(
+            trie.longest_prefix("/static").map(|(_, v)| *v),
+            Some("root")
+        );
+        assert_eq!(trie.longest_prefix("static"), None);
+    }
+
+    #[test]
+    fn test_byte_keys_and_values_mut() {
+        let mut trie = TrieMap::new();
+        trie.insert(vec![1u8, 2], 10);
+        trie.insert(vec![1u8], 20);
+
+        for value in trie.values_mut() {
+            *value += 1;
+        }
+        assert_eq!(trie.get(&[1u8, 2]), Some(&11));
+        assert_eq!(Map::get(&trie, &vec![1u8]), Some(&21));
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(feature = "std")]
+mod map_view_tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use collections2::{Collection, Filtered, Iterable, KeySet, Map, Mapped, Set, Values};
+
+    fn count_in<S: Set<u32>>(set: &S, probes: &[u32]) -> usize {
+        probes.iter().filter(|p| set.get(p).is_some()).count()
+    }
+
+    #[test]
+    fn test_key_set_is_a_set() {
+        let mut map = HashMap::new();
+        map.insert(1, "one");
+        map.insert(2, "two");
+
+        let keys = KeySet::new(&map);
+        assert_eq!(Set::len(&keys), 2);
+        assert!(keys.contains(&1));
+        assert!(!keys.contains(&3));
+        assert_eq!(count_in(&keys, &[1, 2, 3]), 2);
+    }
+
+    #[test]
+    fn test_values_is_a_collection() {
+        let mut map = BTreeMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        let values = Values::new(&map);
+        assert_eq!(Collection::len(&values), 2);
+        assert!(values.contains(&2));
+        assert_eq!(values.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_filtered_map() {
+        let mut map = BTreeMap::new();
+        for k in 1..=6 {
+            map.insert(k, k * 10);
+        }
+
+        let even = Filtered::new(&map, |(k, _): &(&u32, &u32)| **k % 2 == 0);
+        assert_eq!(Map::len(&even), 3);
+        assert_eq!(even.get(&2), Some(&20));
+        assert_eq!(even.get(&3), None);
+        assert_eq!(even.keys().copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+        assert_eq!(even.values().copied().collect::<Vec<_>>(), vec![20, 40, 60]);
+    }
+
+    #[test]
+    fn test_filtered_and_mapped_list() {
+        let list = vec![1, 2, 3, 4, 5];
+
+        let odd = Filtered::new(&list, |x: &&u32| **x % 2 == 1);
+        assert_eq!(Collection::len(&odd), 3);
+        assert!(odd.contains(&5));
+        assert!(!odd.contains(&4));
+
+        let doubled = Mapped::new(&list, |x: &u32| x * 2);
+        assert_eq!(doubled.iter().collect::<Vec<_>>(), vec![2, 4, 6, 8, 10]);
+    }
+}